@@ -1,2 +1,69 @@
 pub mod async_client;
+mod multipart;
 pub mod sync_client;
+
+use crate::Result;
+use futures_timer::Delay;
+use isahc::http::StatusCode;
+use std::{error::Error, future::Future, thread, time::Duration};
+
+/// Controls how often, and for how long, the client polls Buzz while waiting on a long running
+/// process such as video transcoding.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PollOptions {
+    /// How long to wait between each request
+    pub interval: Duration,
+    /// How many requests to make before giving up
+    pub max_attempts: u32,
+}
+
+impl Default for PollOptions {
+    fn default() -> Self {
+        PollOptions {
+            interval: Duration::from_secs(5),
+            max_attempts: 120,
+        }
+    }
+}
+
+impl PollOptions {
+    /// Call check until it returns a value, sleeping between attempts. Returns None if every
+    /// attempt is used up.
+    pub(crate) fn poll_sync<T, F>(self, mut check: F) -> Result<Option<T>>
+    where
+        F: FnMut() -> Result<Option<T>>,
+    {
+        for attempt in 0..self.max_attempts {
+            if attempt > 0 {
+                thread::sleep(self.interval);
+            }
+            if let Some(value) = check()? {
+                return Ok(Some(value));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Await check until it returns a value, waiting between attempts. Returns None if every
+    /// attempt is used up.
+    pub(crate) async fn poll_async<T, F, Fut>(self, mut check: F) -> Result<Option<T>>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<Option<T>>>,
+    {
+        for attempt in 0..self.max_attempts {
+            if attempt > 0 {
+                Delay::new(self.interval).await;
+            }
+            if let Some(value) = check().await? {
+                return Ok(Some(value));
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// The error for a request Buzz did not accept, keeping the response body that explains why
+pub(crate) fn response_error(url: &str, status: StatusCode, body: &str) -> Box<dyn Error> {
+    format!("{} returned {}: {}", url, status, body).into()
+}
@@ -1,13 +1,16 @@
+use futures_util::io::{copy, empty, AsyncRead, AsyncWrite};
 use isahc::{HttpClient, ResponseExt};
 use rand::Rng;
 use serde_urlencoded::to_string as to_url;
 
+use crate::client::{multipart, response_error};
 use crate::resource::{
-    authenticate::Authenticate, AnyResource, Create, Delete, Read, Resource, ResponseId,
-    ResponseResource,
+    any_resource, authenticate::Authenticate, AnyResource, Create, Delete, Read, Resource,
+    ResponseId, ResponseResource, Upload,
 };
 use crate::Result;
 use isahc::prelude::Request;
+use std::sync::{Mutex, MutexGuard};
 
 /// Creates the BeeswaxApi client. This type is instantiated from the BeeswaxApi struct.
 pub struct AsyncBeeswaxClientBuilder {
@@ -99,40 +102,139 @@ impl AsyncBeeswaxClient {
 
         Ok(())
     }
+
+    /// Upload a file to a given resource
+    pub async fn upload<R: Resource, U: Upload<R>>(&self, upload: &U) -> Result<()> {
+        let url = format!(
+            "{}/rest/{}/upload/{}",
+            &self.base_url,
+            R::NAME,
+            upload.upload_id()
+        );
+        let (content_type, body) =
+            multipart::file_body(U::FIELD_NAME, upload.file_name(), upload.file_content());
+        let request = Request::post(&url)
+            .header("Content-Type", content_type)
+            .body(body)?;
+        let mut response = self.client.send_async(request).await?;
+
+        if !response.status().is_success() {
+            return Err(response_error(&url, response.status(), &response.text()?));
+        }
+
+        Ok(())
+    }
+
+    /// Download the file belonging to a given resource, returning the number of bytes written
+    pub async fn download<R: Resource, W: AsyncWrite + Unpin>(
+        &self,
+        id: u64,
+        writer: &mut W,
+    ) -> Result<u64> {
+        let mut reader = self.download_reader::<R>(id).await?;
+        Ok(copy(&mut reader, writer).await?)
+    }
+
+    /// Open the file belonging to a given resource for reading as it downloads
+    pub async fn download_reader<R: Resource>(
+        &self,
+        id: u64,
+    ) -> Result<Box<dyn AsyncRead + Unpin + Send>> {
+        let url = format!("{}/rest/{}/download/{}", &self.base_url, R::NAME, id);
+        let mut response = self.client.get_async(&url).await?;
+
+        if !response.status().is_success() {
+            return Err(response_error(&url, response.status(), &response.text()?));
+        }
+
+        Ok(Box::new(response.into_body()))
+    }
 }
 
 pub struct AsyncInMemoryClient {
-    store: Vec<AnyResource>,
+    store: Mutex<Vec<AnyResource>>,
 }
 
 impl AsyncInMemoryClient {
     pub fn new() -> AsyncInMemoryClient {
-        AsyncInMemoryClient { store: Vec::new() }
+        AsyncInMemoryClient {
+            store: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Add a resource to the store as it is, e.g. canned View rows or resources with known IDs
+    pub fn insert<R: Resource>(&self, resource: R) {
+        self.store().push(resource.into());
+    }
+
+    /// The store is only locked while a request runs, so a panic part way through one leaves it
+    /// as usable as a panic would leave a Vec
+    fn store(&self) -> MutexGuard<'_, Vec<AnyResource>> {
+        self.store.lock().unwrap_or_else(|error| error.into_inner())
     }
 
     pub async fn read<R: Resource, F: Read<R>>(&self, criteria: &F) -> Result<Vec<R>> {
         let vec = self
-            .store
+            .store()
             .iter() // Inefficient
             .filter_map(|r| R::from_any_resource(r))
             .filter(|r| &criteria == r)
             .map(|r| r.clone())
             .collect();
-        Ok(vec)
+        Ok(criteria.select(vec))
     }
 
-    pub async fn create<R: Resource, C: Create<R>>(&mut self, create: &C) -> Result<R> {
+    pub async fn create<R: Resource, C: Create<R>>(&self, create: &C) -> Result<R> {
         let mut rng = rand::thread_rng();
-        let resource = create.clone().into_resource(rng.gen_range(1, 100000));
-        self.store.push(resource.clone().into());
+        let mut resource = create.clone().into_resource(rng.gen_range(1, 100000));
+        let mut store = self.store();
+        create.create_into(&mut resource, &mut store);
+        store.push(resource.clone().into());
         Ok(resource)
     }
 
     pub async fn update<'a, R: Resource>(&self, resource: &'a R) -> Result<&'a R> {
+        any_resource::update(&mut self.store(), resource);
         Ok(resource)
     }
 
-    pub async fn delete<R: Resource, D: Delete<R>>(&self, _delete: &D) -> Result<()> {
+    pub async fn delete<R: Resource, D: Delete<R>>(&self, delete: &D) -> Result<()> {
+        any_resource::delete(&mut self.store(), delete);
         Ok(())
     }
+
+    pub async fn upload<R: Resource, U: Upload<R>>(&self, upload: &U) -> Result<()> {
+        any_resource::upload(&mut self.store(), upload)
+    }
+
+    pub async fn download<R: Resource, W: AsyncWrite + Unpin>(
+        &self,
+        _id: u64,
+        _writer: &mut W,
+    ) -> Result<u64> {
+        Ok(0)
+    }
+
+    pub async fn download_reader<R: Resource>(
+        &self,
+        _id: u64,
+    ) -> Result<Box<dyn AsyncRead + Unpin + Send>> {
+        Ok(Box::new(empty()))
+    }
 }
+
+/// Implement the same helper methods on both async clients. Their requests take the same
+/// arguments, so a helper written once against them runs unchanged on either.
+macro_rules! impl_async_clients {
+    ($($item:item)*) => {
+        impl $crate::client::async_client::AsyncBeeswaxClient {
+            $($item)*
+        }
+
+        impl $crate::client::async_client::AsyncInMemoryClient {
+            $($item)*
+        }
+    };
+}
+
+pub(crate) use impl_async_clients;
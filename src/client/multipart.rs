@@ -0,0 +1,26 @@
+//! Minimal multipart/form-data encoding for uploading files to Buzz
+
+use rand::{distributions::Alphanumeric, thread_rng, Rng};
+
+/// Encode a single file as a multipart/form-data body, returning the content type header value
+/// and the body
+pub(crate) fn file_body(field_name: &str, file_name: &str, content: &[u8]) -> (String, Vec<u8>) {
+    let boundary: String = thread_rng().sample_iter(&Alphanumeric).take(30).collect();
+    let boundary = format!("beeswax-{}", boundary);
+
+    let mut body = Vec::with_capacity(content.len() + 256);
+    body.extend_from_slice(format!("--{}\r\n", boundary).as_bytes());
+    body.extend_from_slice(
+        format!(
+            "Content-Disposition: form-data; name=\"{}\"; filename=\"{}\"\r\n",
+            field_name,
+            file_name.replace('"', "")
+        )
+        .as_bytes(),
+    );
+    body.extend_from_slice(b"Content-Type: application/octet-stream\r\n\r\n");
+    body.extend_from_slice(content);
+    body.extend_from_slice(format!("\r\n--{}--\r\n", boundary).as_bytes());
+
+    (format!("multipart/form-data; boundary={}", boundary), body)
+}
@@ -2,19 +2,40 @@ use isahc::{HttpClient, ResponseExt};
 use rand::Rng;
 use serde_urlencoded::to_string as to_url;
 
+use crate::client::{multipart, response_error};
 use crate::resource::{
-    authenticate::Authenticate, AnyResource, Create, Delete, Read, Resource, ResponseId,
-    ResponseResource,
+    any_resource,
+    authenticate::Authenticate,
+    common::ViewName,
+    view::{ReadView, View},
+    AnyResource, Create, Delete, Read, Resource, ResponseId, ResponseResource, Upload,
 };
 use crate::Result;
 use isahc::prelude::Request;
+use serde::de::DeserializeOwned;
 use std::cell::RefCell;
+use std::io::{self, Read as IoRead, Write};
 
 pub trait SyncClient {
     fn read<R: Resource, F: Read<R>>(&self, criteria: &F) -> Result<Vec<R>>;
     fn create<R: Resource, C: Create<R>>(&self, create: &C) -> Result<R>;
     fn update<'a, R: Resource>(&self, resource: &'a R) -> Result<&'a R>;
     fn delete<R: Resource, D: Delete<R>>(&self, delete: &D) -> Result<()>;
+    fn upload<R: Resource, U: Upload<R>>(&self, upload: &U) -> Result<()>;
+    fn download<R: Resource, W: Write>(&self, id: u64, writer: &mut W) -> Result<u64>;
+    fn download_reader<R: Resource>(&self, id: u64) -> Result<Box<dyn IoRead + Send>>;
+
+    /// Read every row of a View, deserializing each into the given type
+    fn read_view<T: DeserializeOwned>(&self, view_name: ViewName) -> Result<Vec<T>> {
+        self.read_view_rows(&ReadView::builder().view_name(view_name).build())
+    }
+
+    /// Read the rows of a View matching the search criteria, deserializing each into the given
+    /// type
+    fn read_view_rows<T: DeserializeOwned>(&self, read_view: &ReadView) -> Result<Vec<T>> {
+        let views = self.read(read_view)?;
+        views.into_iter().map(View::into_row).collect()
+    }
 }
 
 /// Creates the BeeswaxApi client. This type is instantiated from the BeeswaxApi struct.
@@ -108,6 +129,45 @@ impl SyncClient for SyncBeeswaxClient {
 
         Ok(())
     }
+
+    /// Upload a file to a given resource
+    fn upload<R: Resource, U: Upload<R>>(&self, upload: &U) -> Result<()> {
+        let url = format!(
+            "{}/rest/{}/upload/{}",
+            &self.base_url,
+            R::NAME,
+            upload.upload_id()
+        );
+        let (content_type, body) =
+            multipart::file_body(U::FIELD_NAME, upload.file_name(), upload.file_content());
+        let request = Request::post(&url)
+            .header("Content-Type", content_type)
+            .body(body)?;
+        let mut response = self.client.send(request)?;
+
+        if !response.status().is_success() {
+            return Err(response_error(&url, response.status(), &response.text()?));
+        }
+
+        Ok(())
+    }
+
+    /// Download the file belonging to a given resource, returning the number of bytes written
+    fn download<R: Resource, W: Write>(&self, id: u64, writer: &mut W) -> Result<u64> {
+        Ok(io::copy(&mut self.download_reader::<R>(id)?, writer)?)
+    }
+
+    /// Open the file belonging to a given resource for reading as it downloads
+    fn download_reader<R: Resource>(&self, id: u64) -> Result<Box<dyn IoRead + Send>> {
+        let url = format!("{}/rest/{}/download/{}", &self.base_url, R::NAME, id);
+        let mut response = self.client.get(&url)?;
+
+        if !response.status().is_success() {
+            return Err(response_error(&url, response.status(), &response.text()?));
+        }
+
+        Ok(Box::new(response.into_body()))
+    }
 }
 
 pub struct SyncInMemoryClient {
@@ -118,6 +178,11 @@ impl SyncInMemoryClient {
     pub fn new() -> SyncInMemoryClient {
         SyncInMemoryClient { store: RefCell::new(Vec::new()) }
     }
+
+    /// Add a resource to the store as it is, e.g. canned View rows or resources with known IDs
+    pub fn insert<R: Resource>(&self, resource: R) {
+        self.store.borrow_mut().push(resource.into());
+    }
 }
 
 impl SyncClient for SyncInMemoryClient {
@@ -129,21 +194,37 @@ impl SyncClient for SyncInMemoryClient {
             .filter(|r| &criteria == r)
             .map(|r| r.clone())
             .collect();
-        Ok(vec)
+        Ok(criteria.select(vec))
     }
 
     fn create<R: Resource, C: Create<R>>(&self, create: &C) -> Result<R> {
         let mut rng = rand::thread_rng();
-        let resource = create.clone().into_resource(rng.gen_range(1, 100000));
-        self.store.borrow_mut().push(resource.clone().into());
+        let mut resource = create.clone().into_resource(rng.gen_range(1, 100000));
+        let mut store = self.store.borrow_mut();
+        create.create_into(&mut resource, &mut store);
+        store.push(resource.clone().into());
         Ok(resource)
     }
 
     fn update<'a, R: Resource>(&self, resource: &'a R) -> Result<&'a R> {
+        any_resource::update(&mut self.store.borrow_mut(), resource);
         Ok(resource)
     }
 
-    fn delete<R: Resource, D: Delete<R>>(&self, _delete: &D) -> Result<()> {
+    fn delete<R: Resource, D: Delete<R>>(&self, delete: &D) -> Result<()> {
+        any_resource::delete(&mut self.store.borrow_mut(), delete);
         Ok(())
     }
+
+    fn upload<R: Resource, U: Upload<R>>(&self, upload: &U) -> Result<()> {
+        any_resource::upload(&mut self.store.borrow_mut(), upload)
+    }
+
+    fn download<R: Resource, W: Write>(&self, _id: u64, _writer: &mut W) -> Result<u64> {
+        Ok(0)
+    }
+
+    fn download_reader<R: Resource>(&self, _id: u64) -> Result<Box<dyn IoRead + Send>> {
+        Ok(Box::new(io::empty()))
+    }
 }
@@ -1,4 +1,4 @@
 pub use crate::resource::{
-    Resource, Read, Create, Delete
+    Resource, Read, Create, Delete, Upload
 };
 pub use crate::client::sync_client::SyncClient;
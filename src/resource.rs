@@ -1,26 +1,86 @@
 //! A collection of resources to use with the api
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
+pub mod account;
 pub mod account_alert;
 pub mod advertiser;
-mod any_resource;
+pub mod alert;
+pub(crate) mod any_resource;
+pub mod app_list;
 pub mod authenticate;
+pub mod bid_modifier;
 pub mod campaign;
+pub mod campaign_preset;
 pub mod common;
 pub mod creative;
+pub mod creative_addon;
+pub mod creative_approval;
+pub mod creative_content;
 pub mod creative_line_item;
+pub mod creative_preview;
+pub mod creative_rule;
+pub mod creative_template;
+pub mod deal;
+pub mod deal_list;
+pub mod delivery_modifier;
+pub mod domain_list;
+pub mod event;
+pub mod event_tag;
+pub mod inventory_list;
 pub mod line_item;
+pub mod line_item_flight;
+pub mod line_item_preset;
+mod list_item;
+mod modifier_term;
+pub mod lookup_cache;
+pub mod native_offer;
+pub mod report_output;
+pub mod report_queue;
+pub mod role;
+pub mod segment_tag;
+pub mod test_group;
+pub mod test_plan;
+pub mod user;
+pub mod video_asset;
 pub mod view;
 pub mod view_list;
+pub mod view_row;
 
+pub use account::Account;
 pub use account_alert::AccountAlert;
 pub use advertiser::Advertiser;
+pub use alert::Alert;
 pub use any_resource::{AnyResource, FromAnyResource};
+pub use app_list::AppList;
 pub use authenticate::Authenticate;
+pub use bid_modifier::BidModifier;
 pub use campaign::Campaign;
+pub use campaign_preset::CampaignPreset;
 pub use creative::Creative;
+pub use creative_addon::CreativeAddon;
+pub use creative_approval::CreativeApproval;
 pub use creative_line_item::CreativeLineItem;
+pub use creative_preview::CreativePreviewToken;
+pub use creative_rule::CreativeRule;
+pub use creative_template::CreativeTemplate;
+pub use deal::Deal;
+pub use deal_list::DealList;
+pub use delivery_modifier::DeliveryModifier;
+pub use domain_list::DomainList;
+pub use event::Event;
+pub use event_tag::EventTag;
+pub use inventory_list::InventoryList;
 pub use line_item::LineItem;
+pub use line_item_flight::LineItemFlight;
+pub use line_item_preset::LineItemPreset;
+pub use native_offer::NativeOffer;
+pub use report_queue::ReportQueue;
+pub use role::Role;
+pub use segment_tag::SegmentTag;
+pub use test_group::TestGroup;
+pub use test_plan::TestPlan;
+pub use user::User;
+pub use video_asset::VideoAsset;
 pub use view::View;
 pub use view_list::ViewList;
 
@@ -43,6 +103,12 @@ pub trait Resource:
 }
 
 pub trait Read<R: Resource>: Clone + Serialize + Sync + PartialEq<R> {
+    /// Apply any paging or field selection Buzz would do to the matching resources. Only used by
+    /// the in-memory clients.
+    fn select(&self, resources: Vec<R>) -> Vec<R> {
+        resources
+    }
+
     fn read_sync<C: SyncClient>(&self, beeswax_client: &C) -> Result<Vec<R>> {
         beeswax_client.read(self)
     }
@@ -55,6 +121,10 @@ pub trait Read<R: Resource>: Clone + Serialize + Sync + PartialEq<R> {
 pub trait Create<R: Resource>: Clone + Serialize + Sync {
     fn into_resource(self, id: u64) -> R;
 
+    /// Apply what Buzz does once the resource is created, e.g. running a report, to the resource
+    /// and to the other stored resources. Only used by the in-memory clients.
+    fn create_into(&self, _resource: &mut R, _store: &mut [AnyResource]) {}
+
     fn create_sync<C: SyncClient>(&self, beeswax_client: &C) -> Result<R> {
         beeswax_client.create(self)
     }
@@ -74,6 +144,30 @@ pub trait Delete<R: Resource>: Clone + Serialize + Sync {
     // }
 }
 
+pub trait Upload<R: Resource>: Clone + Sync {
+    /// The name of the multipart form field Buzz expects the file in
+    const FIELD_NAME: &'static str;
+
+    /// The ID of the resource the file is being uploaded to
+    fn upload_id(&self) -> u64;
+
+    /// The name of the file being uploaded
+    fn file_name(&self) -> &str;
+
+    /// The raw content of the file being uploaded
+    fn file_content(&self) -> &[u8];
+
+    /// Apply the file to the resource the way Buzz does once it is uploaded, or return the error
+    /// Buzz would reject the file with. Only used by the in-memory clients.
+    fn upload_into(&self, _resource: &mut R) -> Result<()> {
+        Ok(())
+    }
+
+    fn upload_sync<C: SyncClient>(&self, beeswax_client: &C) -> Result<()> {
+        beeswax_client.upload(self)
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ResponseResource<R: Resource> {
     pub success: bool,
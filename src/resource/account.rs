@@ -0,0 +1,152 @@
+//! An Account holds every other object in Buzz, and is the unit Users log in to. Accounts can be
+//! created and updated by super users, but cannot be deleted, only made inactive.
+
+use crate::resource::{common::Currency, Create, Read, Resource};
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct Account {
+    /// Unique ID of the Account
+    pub account_id: u64,
+
+    /// Name of the Account, e.g. "Example Agency"
+    pub account_name: String,
+
+    /// The Account this Account was created under, if any
+    pub parent_account_id: Option<u64>,
+
+    /// Currency used by default for objects in the Account
+    pub default_currency: Option<Currency>,
+
+    /// An alternative id to lookup the Account, if desired
+    pub alternative_id: Option<String>,
+
+    /// Notes about the Account, up to 255 chars
+    pub notes: Option<String>,
+
+    /// Is the Account active? Users cannot log in to inactive Accounts.
+    pub active: Option<bool>,
+
+    // Undocumented
+    pub create_date: Option<String>,
+    pub update_date: Option<String>,
+}
+
+impl Account {
+    /// Create a builder for CreateAccount
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::Account;
+    ///
+    /// let create_account = Account::create_builder()
+    ///     .account_name("Example Agency")
+    ///     .build();
+    ///
+    /// let account = beeswax_client.create(&create_account).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_builder() -> CreateAccountBuilder<((), (), (), (), (), ())> {
+        CreateAccount::builder()
+    }
+
+    /// Create a builder for ReadAccount
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::Account;
+    ///
+    /// let read_account = Account::read_builder()
+    ///     .account_name("Example Agency".to_string())
+    ///     .build();
+    ///
+    /// let accounts = beeswax_client.read(&read_account).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadAccountBuilder<((), (), (), ())> {
+        ReadAccount::builder()
+    }
+}
+
+impl Resource for Account {
+    const NAME: &'static str = "account";
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadAccount {
+    /// Unique ID of the Account
+    #[builder(default, setter(into))]
+    pub account_id: Option<u64>,
+    /// Name of the Account. Supports %LIKE% syntax
+    #[builder(default, setter(into))]
+    pub account_name: Option<String>,
+    /// An alternative id to lookup the Account, if desired
+    #[builder(default, setter(into))]
+    pub alternative_id: Option<String>,
+    /// Is the Account active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Read<Account> for ReadAccount {}
+
+impl PartialEq<Account> for ReadAccount {
+    fn eq(&self, other: &Account) -> bool {
+        (self.account_id.is_none() || self.account_id == Some(other.account_id))
+            && (self.account_name.is_none()
+                || self.account_name.as_ref() == Some(&other.account_name))
+            && (self.alternative_id.is_none() || self.alternative_id == other.alternative_id)
+            && (self.active.is_none() || self.active == other.active)
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct CreateAccount {
+    /// Name of the Account, e.g. "Example Agency"
+    #[builder(setter(into))]
+    pub account_name: String,
+
+    /// The Account to create this Account under, if any
+    #[builder(default, setter(into))]
+    pub parent_account_id: Option<u64>,
+
+    /// Currency used by default for objects in the Account
+    #[builder(default, setter(into))]
+    pub default_currency: Option<Currency>,
+
+    /// An alternative id to lookup the Account, if desired
+    #[builder(default, setter(into))]
+    pub alternative_id: Option<String>,
+
+    /// Notes about the Account, up to 255 chars
+    #[builder(default, setter(into))]
+    pub notes: Option<String>,
+
+    /// Is the Account active? Users cannot log in to inactive Accounts.
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Create<Account> for CreateAccount {
+    fn into_resource(self, account_id: u64) -> Account {
+        Account {
+            account_id,
+            account_name: self.account_name,
+            parent_account_id: self.parent_account_id,
+            default_currency: self.default_currency,
+            alternative_id: self.alternative_id,
+            notes: self.notes,
+            active: self.active,
+            ..Default::default()
+        }
+    }
+}
@@ -1,7 +1,44 @@
 //! Determine which emails or Slack channels to forward to when receiving an Alert
 
-use crate::resource::{Create, Delete, Resource};
+use crate::resource::{Create, Delete, Read, Resource};
 use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+/// The type of alert an Account Alert forwards, as found in the system_alerts view. Keys not known
+/// to this crate deserialize to Custom.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SystemAlertKey {
+    /// Buzz detected a bad ad, such as one with malware or a broken landing page
+    BadAd,
+    /// A Creative was rejected by an exchange or vendor audit
+    CreativeRejected,
+    /// A Creative was approved by an exchange or vendor audit
+    CreativeApproved,
+    /// A Campaign spent its budget
+    CampaignBudgetReached,
+    /// A Line Item spent its budget
+    LineItemBudgetReached,
+    /// A Campaign is about to reach its end date
+    CampaignEndDate,
+    /// A Line Item is about to reach its end date
+    LineItemEndDate,
+    /// A Segment upload failed to process
+    SegmentUploadFailed,
+    /// A queued report failed to run
+    ReportFailed,
+    /// An alert type not known to this crate, by key
+    #[serde(untagged)]
+    Custom(String),
+}
+
+/// An empty key, as an unset system_alert_key was before keys were typed. Buzz rejects it, so
+/// set a real key before creating an Account Alert.
+impl Default for SystemAlertKey {
+    fn default() -> Self {
+        SystemAlertKey::Custom(String::new())
+    }
+}
 
 #[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
 pub struct AccountAlert {
@@ -10,7 +47,7 @@ pub struct AccountAlert {
 
     /// key representing the type of alert. For example bad_ad alert will send when Buzz detects a
     /// bad ad.
-    pub system_alert_key: Option<String>,
+    pub system_alert_key: Option<SystemAlertKey>,
 
     /// One or more emails to forward the alert to, separated by commas
     pub email: Option<String>,
@@ -28,31 +65,124 @@ pub struct AccountAlert {
     pub active: Option<bool>,
 }
 
+impl AccountAlert {
+    /// Create a builder for CreateAccountAlert
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::{account_alert::SystemAlertKey, AccountAlert};
+    ///
+    /// let create_account_alert = AccountAlert::create_builder()
+    ///     .system_alert_key(SystemAlertKey::BadAd)
+    ///     .email("ops@example.com".to_string())
+    ///     .build();
+    ///
+    /// let account_alert = beeswax_client.create(&create_account_alert).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_builder() -> CreateAccountAlertBuilder<((), (), (), (), (), ())> {
+        CreateAccountAlert::builder()
+    }
+
+    /// Create a builder for ReadAccountAlert
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::{account_alert::SystemAlertKey, AccountAlert};
+    ///
+    /// let read_account_alert = AccountAlert::read_builder()
+    ///     .system_alert_key(SystemAlertKey::BadAd)
+    ///     .build();
+    ///
+    /// let account_alerts = beeswax_client.read(&read_account_alert).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadAccountAlertBuilder<((), (), ())> {
+        ReadAccountAlert::builder()
+    }
+
+    /// Create a builder for DeleteAccountAlert
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::AccountAlert;
+    ///
+    /// let delete_account_alert = AccountAlert::delete_builder()
+    ///     .account_alert_id(10)
+    ///     .build();
+    ///
+    /// beeswax_client.delete(&delete_account_alert).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_builder() -> DeleteAccountAlertBuilder<((),)> {
+        DeleteAccountAlert::builder()
+    }
+}
+
 impl Resource for AccountAlert {
     const NAME: &'static str = "account_alert";
 }
 
-// ToDo: Find out if there is a way to Read the account alert
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadAccountAlert {
+    /// Unique ID of the account_alert
+    #[builder(default, setter(into))]
+    pub account_alert_id: Option<u64>,
+    /// key representing the type of alert
+    #[builder(default, setter(into))]
+    pub system_alert_key: Option<SystemAlertKey>,
+    /// Is the Account Alert currently active
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Read<AccountAlert> for ReadAccountAlert {}
+
+impl PartialEq<AccountAlert> for ReadAccountAlert {
+    fn eq(&self, other: &AccountAlert) -> bool {
+        (self.account_alert_id.is_none() || self.account_alert_id == Some(other.account_alert_id))
+            && (self.system_alert_key.is_none() || self.system_alert_key == other.system_alert_key)
+            && (self.active.is_none() || self.active == other.active)
+    }
+}
 
-#[derive(Clone, Default, Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Default, Deserialize, Serialize, TypedBuilder)]
 pub struct CreateAccountAlert {
     /// key representing the type of alert. For example bad_ad alert will send when Buzz detects a
     /// bad ad.
-    pub system_alert_key: String,
+    #[builder(setter(into))]
+    pub system_alert_key: SystemAlertKey,
 
     /// One or more emails to forward the alert to, separated by commas
+    #[builder(default, setter(into))]
     pub email: Option<String>,
 
     /// Slack API key
+    #[builder(default, setter(into))]
     pub slack_api: Option<String>,
 
     /// Slack channel such as #buzz-alerts
+    #[builder(default, setter(into))]
     pub slack_channel: Option<String>,
 
     /// Emoji to post next to alert in slack, such as :poop:
+    #[builder(default, setter(into))]
     pub slack_emoji: Option<String>,
 
     /// Is the Account Alert currently active
+    #[builder(default, setter(into))]
     pub active: Option<bool>,
 }
 
@@ -70,10 +200,21 @@ impl Create<AccountAlert> for CreateAccountAlert {
     }
 }
 
-#[derive(Clone, Debug, Serialize)]
+#[derive(Clone, Debug, Serialize, TypedBuilder)]
 pub struct DeleteAccountAlert {
     /// Unique ID of the account_alert
     account_alert_id: u64,
 }
 
 impl Delete<AccountAlert> for DeleteAccountAlert {}
+
+impl Delete<AccountAlert> for AccountAlert {}
+
+/// Create a delete request for an existing Account Alert
+impl From<&AccountAlert> for DeleteAccountAlert {
+    fn from(account_alert: &AccountAlert) -> Self {
+        DeleteAccountAlert {
+            account_alert_id: account_alert.account_alert_id,
+        }
+    }
+}
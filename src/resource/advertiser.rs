@@ -28,7 +28,7 @@ pub struct Advertiser {
     /// The conversion attribution to use for Events owned by this Advertiser. Must be a valid
     /// attribution method as found in the conversion_atrtibution_methods view. At this time only
     /// method 1 (last click) is supported. Once a conversion method is chosen, it cannot be
-    /// changed.
+    /// changed. See Event::attribution for the windows used.
     pub conversion_method_id: ConversionMethod,
     /// Click URL to use by default for objects created under this advertiser
     pub default_click_url: Option<String>,
@@ -177,7 +177,7 @@ pub struct CreateAdvertiser {
     /// The conversion attribution to use for Events owned by this Advertiser. Must be a valid
     /// attribution method as found in the conversion_atrtibution_methods view. At this time only
     /// method 1 (last click) is supported. Once a conversion method is chosen, it cannot be
-    /// changed.
+    /// changed. See Event::attribution for the windows used.
     #[builder(default)]
     pub conversion_method_id: ConversionMethod,
     /// Click URL to use by default for objects created under this advertiser
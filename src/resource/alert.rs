@@ -0,0 +1,270 @@
+//! An Alert is raised by Buzz when something needs attention, such as a bad ad being detected, a
+//! budget running out or a Creative being rejected. Alerts are created by Buzz, so they can only be
+//! read, and acknowledged once handled. Where Alerts are forwarded is set with AccountAlert.
+//!
+//! New Alerts can be followed with an AlertCursor, which remembers the last Alert seen.
+
+use crate::client::{async_client::impl_async_clients, sync_client::SyncClient, PollOptions};
+use crate::resource::{
+    account_alert::SystemAlertKey, common::SortOrder, role::ObjectType, Read, Resource,
+};
+use crate::Result;
+use serde::{Deserialize, Serialize, Serializer};
+use typed_builder::TypedBuilder;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct Alert {
+    /// Unique ID of the Alert. IDs increase as Alerts are raised.
+    pub alert_id: u64,
+
+    /// key representing the type of alert, e.g. bad_ad
+    pub system_alert_key: Option<SystemAlertKey>,
+
+    /// Description of what happened
+    pub alert_message: Option<String>,
+
+    /// Type of the object the Alert is about, if any
+    pub object_type: Option<ObjectType>,
+
+    /// ID of the object the Alert is about, if any
+    pub object_id: Option<u64>,
+
+    /// When the Alert was raised
+    pub alert_date: Option<String>,
+
+    /// Has the Alert been handled?
+    pub acknowledged: Option<bool>,
+
+    // Undocumented
+    pub account_id: Option<u64>,
+    pub create_date: Option<String>,
+    pub update_date: Option<String>,
+}
+
+impl Alert {
+    /// Create a builder for ReadAlert
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::Alert;
+    ///
+    /// let read_alert = Alert::read_builder()
+    ///     .acknowledged(false)
+    ///     .build();
+    ///
+    /// let alerts = beeswax_client.read(&read_alert).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn read_builder() -> ReadAlertBuilder<((), (), (), (), (), (), (), ())> {
+        ReadAlert::builder()
+    }
+
+    /// Mark the Alert as handled
+    pub fn acknowledge_sync<C: SyncClient>(&mut self, beeswax_client: &C) -> Result<()> {
+        self.acknowledged = Some(true);
+        beeswax_client.update(self)?;
+        Ok(())
+    }
+}
+
+impl Resource for Alert {
+    const NAME: &'static str = "alert";
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadAlert {
+    /// The Alert, or the Alerts raised after one
+    #[builder(default, setter(into))]
+    pub alert_id: Option<AlertIdFilter>,
+    /// key representing the type of alert
+    #[builder(default, setter(into))]
+    pub system_alert_key: Option<SystemAlertKey>,
+    /// Type of the object the Alert is about
+    #[builder(default, setter(into))]
+    pub object_type: Option<ObjectType>,
+    /// ID of the object the Alert is about
+    #[builder(default, setter(into))]
+    pub object_id: Option<u64>,
+    /// Has the Alert been handled?
+    #[builder(default, setter(into))]
+    pub acknowledged: Option<bool>,
+    /// Field to sort the Alerts by, e.g. "alert_id"
+    #[builder(default, setter(into))]
+    pub sort_by: Option<String>,
+    /// Order to sort the Alerts in
+    #[builder(default, setter(into))]
+    pub order: Option<SortOrder>,
+    /// Maximum number of Alerts to return
+    #[builder(default, setter(into))]
+    pub rows: Option<u64>,
+}
+
+/// Which Alerts to read by ID. Buzz takes a single alert_id filter, so the two can't be combined.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum AlertIdFilter {
+    /// Only the Alert with this ID
+    Equal(u64),
+    /// Only Alerts raised after this one, i.e. with a greater ID
+    GreaterThan(u64),
+}
+
+impl AlertIdFilter {
+    fn matches(self, alert_id: u64) -> bool {
+        match self {
+            AlertIdFilter::Equal(id) => alert_id == id,
+            AlertIdFilter::GreaterThan(id) => alert_id > id,
+        }
+    }
+}
+
+impl Serialize for AlertIdFilter {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        match self {
+            AlertIdFilter::Equal(alert_id) => serializer.serialize_u64(*alert_id),
+            AlertIdFilter::GreaterThan(alert_id) => {
+                serializer.serialize_str(&format!(">{}", alert_id))
+            }
+        }
+    }
+}
+
+impl Read<Alert> for ReadAlert {
+    fn select(&self, mut alerts: Vec<Alert>) -> Vec<Alert> {
+        if self.sort_by.as_deref() == Some("alert_id") {
+            alerts.sort_by_key(|alert| alert.alert_id);
+            if self.order == Some(SortOrder::Desc) {
+                alerts.reverse();
+            }
+        }
+        let rows = self.rows.map(|rows| rows as usize).unwrap_or(usize::MAX);
+        alerts.into_iter().take(rows).collect()
+    }
+}
+
+impl PartialEq<Alert> for ReadAlert {
+    fn eq(&self, other: &Alert) -> bool {
+        (self.alert_id.is_none() || self.alert_id.unwrap().matches(other.alert_id))
+            && (self.system_alert_key.is_none() || self.system_alert_key == other.system_alert_key)
+            && (self.object_type.is_none() || self.object_type == other.object_type)
+            && (self.object_id.is_none() || self.object_id == other.object_id)
+            && (self.acknowledged.is_none()
+                || self.acknowledged == Some(other.acknowledged.unwrap_or(false)))
+    }
+}
+
+/// Follows the Alerts matching some criteria, yielding only those raised since the last call
+/// ```
+/// # use std::error::Error;
+/// # use beeswax::client::async_client::AsyncInMemoryClient;
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn Error>> {
+/// # let mut beeswax_client = AsyncInMemoryClient::new();
+/// use beeswax::resource::alert::AlertCursor;
+///
+/// let mut cursor = AlertCursor::default();
+/// let alerts = beeswax_client.new_alerts(&mut cursor).await?;
+/// for mut alert in alerts {
+///     beeswax_client.acknowledge_alert(&mut alert).await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct AlertCursor {
+    /// ID of the last Alert seen. Only Alerts with a greater ID are yielded.
+    pub last_alert_id: u64,
+    /// Which Alerts to follow
+    pub criteria: ReadAlert,
+}
+
+impl AlertCursor {
+    /// Follow the Alerts matching the criteria, starting after the given Alert ID
+    pub fn new(last_alert_id: u64, criteria: ReadAlert) -> AlertCursor {
+        AlertCursor {
+            last_alert_id,
+            criteria,
+        }
+    }
+
+    /// The criteria to read the Alerts after the cursor, oldest first. Buzz filters and sorts the
+    /// Alerts so that a limited number of rows always returns the oldest new Alerts.
+    pub fn read_alert(&self) -> ReadAlert {
+        ReadAlert {
+            alert_id: Some(AlertIdFilter::GreaterThan(self.last_alert_id)),
+            sort_by: Some("alert_id".to_string()),
+            order: Some(SortOrder::Asc),
+            ..self.criteria.clone()
+        }
+    }
+
+    /// Fetch the Alerts raised since the last call, oldest first
+    pub fn next_sync<C: SyncClient>(&mut self, beeswax_client: &C) -> Result<Vec<Alert>> {
+        let alerts = self.read_alert().read_sync(beeswax_client)?;
+        Ok(self.advance(alerts))
+    }
+
+    /// Poll Buzz until at least one new Alert has been raised. Returns no Alerts if every attempt
+    /// is used up.
+    pub fn poll_sync<C: SyncClient>(
+        &mut self,
+        beeswax_client: &C,
+        poll: PollOptions,
+    ) -> Result<Vec<Alert>> {
+        let alerts = poll.poll_sync(|| {
+            let alerts = self.next_sync(beeswax_client)?;
+            Ok(Some(alerts).filter(|alerts| !alerts.is_empty()))
+        })?;
+        Ok(alerts.unwrap_or_default())
+    }
+
+    /// Keep the Alerts after the cursor, oldest first, and move the cursor past them
+    fn advance(&mut self, alerts: Vec<Alert>) -> Vec<Alert> {
+        let last_alert_id = self.last_alert_id;
+        let mut alerts: Vec<Alert> = alerts
+            .into_iter()
+            .filter(|alert| alert.alert_id > last_alert_id)
+            .collect();
+        alerts.sort_by_key(|alert| alert.alert_id);
+        if let Some(alert) = alerts.last() {
+            self.last_alert_id = alert.alert_id;
+        }
+        alerts
+    }
+}
+
+impl_async_clients! {
+    /// Mark the Alert as handled
+    pub async fn acknowledge_alert(&self, alert: &mut Alert) -> Result<()> {
+        alert.acknowledged = Some(true);
+        self.update(alert).await?;
+        Ok(())
+    }
+
+    /// Fetch the Alerts raised since the cursor, oldest first, and move the cursor past them
+    pub async fn new_alerts(&self, cursor: &mut AlertCursor) -> Result<Vec<Alert>> {
+        let alerts = self.read(&cursor.read_alert()).await?;
+        Ok(cursor.advance(alerts))
+    }
+
+    /// Poll Buzz until at least one Alert has been raised since the cursor. Returns no Alerts if
+    /// every attempt is used up.
+    pub async fn poll_alerts(
+        &self,
+        cursor: &mut AlertCursor,
+        poll: PollOptions,
+    ) -> Result<Vec<Alert>> {
+        let read_alert = cursor.read_alert();
+        let alerts = poll
+            .poll_async(|| async {
+                let alerts = self.read(&read_alert).await?;
+                Ok(Some(alerts).filter(|alerts| !alerts.is_empty()))
+            })
+            .await?;
+        Ok(cursor.advance(alerts.unwrap_or_default()))
+    }
+}
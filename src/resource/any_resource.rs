@@ -1,7 +1,9 @@
 use crate::resource::*;
+use serde_json::Value;
 
 pub trait FromAnyResource {
     fn from_any_resource(r: &AnyResource) -> Option<&Self>;
+    fn from_any_resource_mut(r: &mut AnyResource) -> Option<&mut Self>;
 }
 
 macro_rules! any_resource {
@@ -19,6 +21,13 @@ macro_rules! any_resource {
                         _ => None,
                     }
                 }
+
+                fn from_any_resource_mut(ar: &mut AnyResource) -> Option<&mut $i> {
+                    match ar {
+                        AnyResource::$i(r) => Some(r),
+                        _ => None,
+                    }
+                }
             }
 
             impl From<$i> for AnyResource {
@@ -32,13 +41,93 @@ macro_rules! any_resource {
 }
 
 any_resource!(
+    Account,
     AccountAlert,
     Advertiser,
+    Alert,
+    AppList,
     Authenticate,
+    BidModifier,
     Campaign,
+    CampaignPreset,
     Creative,
+    CreativeAddon,
+    CreativeApproval,
     CreativeLineItem,
+    CreativePreviewToken,
+    CreativeRule,
+    CreativeTemplate,
+    Deal,
+    DealList,
+    DeliveryModifier,
+    DomainList,
+    Event,
+    EventTag,
+    InventoryList,
     LineItem,
+    LineItemFlight,
+    LineItemPreset,
+    NativeOffer,
+    ReportQueue,
+    Role,
+    SegmentTag,
+    TestGroup,
+    TestPlan,
+    User,
+    VideoAsset,
     View,
     ViewList
 );
+
+/// The ID of a resource, read from its `<NAME>_id` field. Used by the in-memory clients to find the
+/// stored copy of a resource, resources without such a field can't be found.
+pub(crate) fn resource_id<R: Resource>(resource: &R) -> Option<u64> {
+    serde_json::to_value(resource)
+        .ok()?
+        .get(format!("{}_id", R::NAME))?
+        .as_u64()
+}
+
+/// The stored resource of type R with the given ID
+pub(crate) fn find_mut<R: Resource>(store: &mut [AnyResource], id: u64) -> Option<&mut R> {
+    store
+        .iter_mut()
+        .filter_map(|r| R::from_any_resource_mut(r))
+        .find(|r| resource_id(*r) == Some(id))
+}
+
+/// Replace the stored copy of the resource, as the in-memory clients' update
+pub(crate) fn update<R: Resource>(store: &mut [AnyResource], resource: &R) {
+    if let Some(stored) = resource_id(resource).and_then(|id| find_mut::<R>(store, id)) {
+        *stored = resource.clone();
+    }
+}
+
+/// Apply the upload to the stored resource it is for, as the in-memory clients' upload
+pub(crate) fn upload<R: Resource, U: Upload<R>>(
+    store: &mut [AnyResource],
+    upload: &U,
+) -> Result<()> {
+    match find_mut::<R>(store, upload.upload_id()) {
+        Some(stored) => upload.upload_into(stored),
+        None => Ok(()),
+    }
+}
+
+/// Remove the stored resources matching every field set on the delete, as the in-memory clients'
+/// delete
+pub(crate) fn delete<R: Resource, D: Delete<R>>(store: &mut Vec<AnyResource>, delete: &D) {
+    let fields: Vec<(String, Value)> = match serde_json::to_value(delete) {
+        Ok(Value::Object(fields)) => fields.into_iter().filter(|(_, v)| !v.is_null()).collect(),
+        _ => return,
+    };
+    if fields.is_empty() {
+        return;
+    }
+    store.retain(|r| {
+        let resource = R::from_any_resource(r).and_then(|r| serde_json::to_value(r).ok());
+        !fields
+            .iter()
+            .all(|(key, value)| resource.as_ref().and_then(|r| r.get(key)) == Some(value))
+    });
+}
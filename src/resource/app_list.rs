@@ -0,0 +1,220 @@
+//! An App List is a set of mobile app bundle IDs, such as "com.example.game", for Targeting
+//! Templates to allow or block. Lists can be edited in bulk and saved with update, or replaced by
+//! uploading a file with one bundle ID per line. The most commonly seen apps are found in the
+//! lookup_apps view.
+
+use crate::resource::{
+    list_item::{self, list_items, normalize_item},
+    Create, Delete, Read, Resource,
+};
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct AppList {
+    /// Unique ID of the App List
+    pub app_list_id: u64,
+
+    /// Name of the App List, e.g. "Kids apps"
+    pub app_list_name: String,
+
+    /// Bundle IDs of the apps in the list, e.g. "com.example.game"
+    pub apps: Vec<String>,
+
+    /// The Advertiser the App List may be used by. If empty, every Advertiser may use the list.
+    pub advertiser_id: Option<u64>,
+
+    /// An alternative id to lookup the App List, if desired
+    pub alternative_id: Option<String>,
+
+    /// Notes about the App List, up to 255 chars
+    pub notes: Option<String>,
+
+    /// Is the App List active?
+    pub active: Option<bool>,
+
+    // Undocumented
+    pub account_id: Option<u64>,
+    pub create_date: Option<String>,
+    pub update_date: Option<String>,
+}
+
+impl AppList {
+    /// Create a builder for CreateAppList
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::AppList;
+    ///
+    /// let create_app_list = AppList::create_builder()
+    ///     .app_list_name("Kids apps")
+    ///     .apps(vec!["com.example.game".to_string()])
+    ///     .build();
+    ///
+    /// let app_list = beeswax_client.create(&create_app_list).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_builder() -> CreateAppListBuilder<((), (), (), (), (), ())> {
+        CreateAppList::builder()
+    }
+
+    /// Create a builder for ReadAppList
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::AppList;
+    ///
+    /// let read_app_list = AppList::read_builder()
+    ///     .app_list_name("Kids apps".to_string())
+    ///     .build();
+    ///
+    /// let app_lists = beeswax_client.read(&read_app_list).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadAppListBuilder<((), (), (), (), ())> {
+        ReadAppList::builder()
+    }
+
+    /// Create a builder for DeleteAppList
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::AppList;
+    ///
+    /// let delete_app_list = AppList::delete_builder()
+    ///     .app_list_id(10)
+    ///     .build();
+    ///
+    /// beeswax_client.delete(&delete_app_list).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_builder() -> DeleteAppListBuilder<((),)> {
+        DeleteAppList::builder()
+    }
+}
+
+impl Resource for AppList {
+    const NAME: &'static str = "app_list";
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadAppList {
+    /// Unique ID of the App List
+    #[builder(default, setter(into))]
+    pub app_list_id: Option<u64>,
+    /// Name of the App List. Supports %LIKE% syntax
+    #[builder(default, setter(into))]
+    pub app_list_name: Option<String>,
+    /// The Advertiser the App List may be used by
+    #[builder(default, setter(into))]
+    pub advertiser_id: Option<u64>,
+    /// An alternative id to lookup the App List, if desired
+    #[builder(default, setter(into))]
+    pub alternative_id: Option<String>,
+    /// Is the App List active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Read<AppList> for ReadAppList {}
+
+impl PartialEq<AppList> for ReadAppList {
+    fn eq(&self, other: &AppList) -> bool {
+        (self.app_list_id.is_none() || self.app_list_id == Some(other.app_list_id))
+            && (self.app_list_name.is_none()
+                || self.app_list_name.as_ref() == Some(&other.app_list_name))
+            && (self.advertiser_id.is_none() || self.advertiser_id == other.advertiser_id)
+            && (self.alternative_id.is_none() || self.alternative_id == other.alternative_id)
+            && (self.active.is_none() || self.active == other.active)
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct CreateAppList {
+    /// Name of the App List, e.g. "Kids apps"
+    #[builder(setter(into))]
+    pub app_list_name: String,
+
+    /// Bundle IDs of the apps in the list, e.g. "com.example.game"
+    #[builder(default)]
+    #[serde(serialize_with = "list_item::serialize_items")]
+    pub apps: Vec<String>,
+
+    /// The Advertiser the App List may be used by. If empty, every Advertiser may use the list.
+    #[builder(default, setter(into))]
+    pub advertiser_id: Option<u64>,
+
+    /// An alternative id to lookup the App List, if desired
+    #[builder(default, setter(into))]
+    pub alternative_id: Option<String>,
+
+    /// Notes about the App List, up to 255 chars
+    #[builder(default, setter(into))]
+    pub notes: Option<String>,
+
+    /// Is the App List active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Create<AppList> for CreateAppList {
+    fn into_resource(self, app_list_id: u64) -> AppList {
+        AppList {
+            app_list_id,
+            app_list_name: self.app_list_name,
+            apps: list_item::normalized_items(self.apps, normalize_item),
+            advertiser_id: self.advertiser_id,
+            alternative_id: self.alternative_id,
+            notes: self.notes,
+            active: self.active,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TypedBuilder)]
+pub struct DeleteAppList {
+    app_list_id: u64,
+}
+
+impl Delete<AppList> for DeleteAppList {}
+
+impl Delete<AppList> for AppList {}
+
+/// Replace the apps of an App List with those in a file, one bundle ID per line
+#[derive(Clone, Debug, TypedBuilder)]
+pub struct UploadAppList {
+    /// Unique ID of the App List the file replaces the apps of
+    pub app_list_id: u64,
+    /// Name of the file, e.g. "apps.txt"
+    #[builder(setter(into))]
+    pub file_name: String,
+    /// Raw content of the file
+    pub content: Vec<u8>,
+}
+
+list_items!(
+    AppList {
+        apps,
+        normalize_item,
+        add_apps,
+        remove_apps
+    },
+    UploadAppList {
+        app_list_id,
+        from_apps,
+        apps
+    }
+);
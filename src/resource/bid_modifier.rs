@@ -0,0 +1,189 @@
+//! A Bid Modifier adjusts the bid of a Campaign or Line Item based on attributes of the auction,
+//! for example bidding 20% more on a given domain. Any Campaign or Line Item using a Bid Modifier
+//! must also set a max_bid.
+
+use crate::resource::{
+    common::TermValue, modifier_term::modifier_term, Create, Delete, Read, Resource,
+};
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+modifier_term!(
+    /// A term of a Bid Modifier, e.g. `BidModifierTerm::new("domain", "example.com", 1.2)`
+    BidModifierTerm {
+        /// Multiplier applied to the bid when the term matches, e.g. 1.2 to bid 20% more
+        modifier
+    }
+);
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct BidModifier {
+    /// Unique ID of the Bid Modifier
+    pub bid_modifier_id: u64,
+
+    /// Name of the Bid Modifier, e.g. "Premium domains"
+    pub bid_modifier_name: String,
+
+    /// The terms to match against the auction. When several terms match, their modifiers are
+    /// multiplied together.
+    pub terms: Vec<BidModifierTerm>,
+
+    /// An alternative id to lookup the Bid Modifier, if desired
+    pub alternative_id: Option<String>,
+
+    /// Notes about the Bid Modifier, up to 255 chars
+    pub notes: Option<String>,
+
+    /// Is the Bid Modifier active?
+    pub active: Option<bool>,
+
+    // Undocumented
+    pub account_id: Option<u64>,
+    pub create_date: Option<String>,
+    pub update_date: Option<String>,
+}
+
+impl BidModifier {
+    /// Create a builder for CreateBidModifier
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::{bid_modifier::BidModifierTerm, BidModifier};
+    ///
+    /// let create_bid_modifier = BidModifier::create_builder()
+    ///     .bid_modifier_name("Some name")
+    ///     .terms(vec![BidModifierTerm::new("domain", "example.com", 1.2)])
+    ///     .build();
+    ///
+    /// let bid_modifier = beeswax_client.create(&create_bid_modifier).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_builder() -> CreateBidModifierBuilder<((), (), (), (), ())> {
+        CreateBidModifier::builder()
+    }
+
+    /// Create a builder for ReadBidModifier
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::BidModifier;
+    ///
+    /// let read_bid_modifier = BidModifier::read_builder()
+    ///     .bid_modifier_name("Some name".to_string())
+    ///     .build();
+    ///
+    /// let bid_modifiers = beeswax_client.read(&read_bid_modifier).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadBidModifierBuilder<((), (), (), ())> {
+        ReadBidModifier::builder()
+    }
+
+    /// Create a builder for DeleteBidModifier
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::BidModifier;
+    ///
+    /// let delete_bid_modifier = BidModifier::delete_builder()
+    ///     .bid_modifier_id(10)
+    ///     .build();
+    ///
+    /// beeswax_client.delete(&delete_bid_modifier).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_builder() -> DeleteBidModifierBuilder<((),)> {
+        DeleteBidModifier::builder()
+    }
+}
+
+impl Resource for BidModifier {
+    const NAME: &'static str = "bid_modifier";
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadBidModifier {
+    /// Unique ID of the Bid Modifier
+    #[builder(default, setter(into))]
+    pub bid_modifier_id: Option<u64>,
+    /// Name of the Bid Modifier. Supports %LIKE% syntax
+    #[builder(default, setter(into))]
+    pub bid_modifier_name: Option<String>,
+    /// An alternative id to lookup the Bid Modifier, if desired
+    #[builder(default, setter(into))]
+    pub alternative_id: Option<String>,
+    /// Is the Bid Modifier active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Read<BidModifier> for ReadBidModifier {}
+
+impl PartialEq<BidModifier> for ReadBidModifier {
+    fn eq(&self, other: &BidModifier) -> bool {
+        (self.bid_modifier_id.is_none() || self.bid_modifier_id == Some(other.bid_modifier_id))
+            && (self.bid_modifier_name.is_none()
+                || self.bid_modifier_name.as_ref() == Some(&other.bid_modifier_name))
+            && (self.alternative_id.is_none() || self.alternative_id == other.alternative_id)
+            && (self.active.is_none() || self.active == other.active)
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct CreateBidModifier {
+    /// Name of the Bid Modifier, e.g. "Premium domains"
+    #[builder(setter(into))]
+    pub bid_modifier_name: String,
+
+    /// The terms to match against the auction. When several terms match, their modifiers are
+    /// multiplied together.
+    #[builder(default)]
+    pub terms: Vec<BidModifierTerm>,
+
+    /// An alternative id to lookup the Bid Modifier, if desired
+    #[builder(default, setter(into))]
+    pub alternative_id: Option<String>,
+
+    /// Notes about the Bid Modifier, up to 255 chars
+    #[builder(default, setter(into))]
+    pub notes: Option<String>,
+
+    /// Is the Bid Modifier active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Create<BidModifier> for CreateBidModifier {
+    fn into_resource(self, bid_modifier_id: u64) -> BidModifier {
+        BidModifier {
+            bid_modifier_id,
+            bid_modifier_name: self.bid_modifier_name,
+            terms: self.terms,
+            alternative_id: self.alternative_id,
+            notes: self.notes,
+            active: self.active,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TypedBuilder)]
+pub struct DeleteBidModifier {
+    bid_modifier_id: u64,
+}
+
+impl Delete<BidModifier> for DeleteBidModifier {}
+
+impl Delete<BidModifier> for BidModifier {}
@@ -83,8 +83,10 @@ pub struct Campaign {
 
     pub campaign_spend: f64,
 
-    // ToDo: Unknown Type
-    // pub test_plan_id: Unknown Type,
+    /// ID of the Test Plan splitting users between the Campaign's Line Items. If set, every Line
+    /// Item must have a test_group_id belonging to the Test Plan.
+    pub test_plan_id: Option<u64>,
+
     // ToDo: Unknown Type
     // pub default_targeting: Unknown Type,
     pub push_status: u64,
@@ -135,6 +137,7 @@ impl Campaign {
         (),
         (),
         (),
+        (),
     )> {
         CreateCampaign::builder()
     }
@@ -156,7 +159,7 @@ impl Campaign {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn read_builder() -> ReadCampaignBuilder<((), (), (), (), (), (), (), (), ())> {
+    pub fn read_builder() -> ReadCampaignBuilder<((), (), (), (), (), (), (), (), (), ())> {
         ReadCampaign::builder()
     }
 
@@ -203,6 +206,9 @@ pub struct ReadCampaign {
     /// ID of the Delivery Modifier associated with this Campaign
     #[builder(default, setter(into))]
     pub delivery_modifier_id: Option<u64>,
+    /// ID of the Test Plan associated with this Campaign
+    #[builder(default, setter(into))]
+    pub test_plan_id: Option<u64>,
     /// An alternative id to lookup the campaign, if desired
     #[builder(default, setter(into))]
     pub alternative_id: Option<String>,
@@ -224,6 +230,7 @@ impl PartialEq<Campaign> for ReadCampaign {
             && (self.bid_modifier_id.is_none() || self.bid_modifier_id == other.bid_modifier_id)
             && (self.delivery_modifier_id.is_none()
                 || self.delivery_modifier_id == other.delivery_modifier_id)
+            && (self.test_plan_id.is_none() || self.test_plan_id == other.test_plan_id)
             && (self.alternative_id.is_none() || self.alternative_id == other.alternative_id)
             && (self.active.is_none() || self.active == Some(other.active))
             && (self.create_date.is_none() || self.create_date == other.create_date)
@@ -320,6 +327,11 @@ pub struct CreateCampaign {
     /// Is it active?
     #[builder(default)]
     pub active: bool,
+
+    /// ID of the Test Plan splitting users between the Campaign's Line Items. If set, every Line
+    /// Item must have a test_group_id belonging to the Test Plan.
+    #[builder(default, setter(into))]
+    pub test_plan_id: Option<u64>,
 }
 
 impl Create<Campaign> for CreateCampaign {
@@ -345,6 +357,7 @@ impl Create<Campaign> for CreateCampaign {
             alternative_id: self.alternative_id,
             notes: self.notes,
             active: self.active,
+            test_plan_id: self.test_plan_id,
             ..Default::default()
         }
     }
@@ -0,0 +1,347 @@
+//! A Campaign Preset holds default values for new Campaigns, such as the budget type, frequency cap
+//! and currency, so house defaults can be kept in one place. Advertisers may set a preset to use by
+//! default with default_campaign_preset_id. Use create_campaign to start a CreateCampaign from the
+//! preset.
+
+use crate::resource::{
+    campaign::CreateCampaign,
+    common::{BudgetType, Continent, Currency, FrequencyCap, FrequencyCapType, RevenueType},
+    Advertiser, Create, Delete, Read, Resource,
+};
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct CampaignPreset {
+    /// Unique ID of the Campaign Preset
+    pub campaign_preset_id: u64,
+
+    /// Name of the Campaign Preset, e.g. "House defaults"
+    pub campaign_preset_name: String,
+
+    /// The Advertiser the preset may be used by. If empty, every Advertiser may use the preset.
+    pub advertiser_id: Option<u64>,
+
+    /// Maximum amount to spend on each Campaign
+    pub campaign_budget: Option<f64>,
+
+    /// Maximum amount to spend or deliver in a day
+    pub daily_budget: Option<f64>,
+
+    /// Type of budget, 0=spend, 1=impressions, 2=spend with vendor fees
+    pub budget_type: Option<BudgetType>,
+
+    /// Supported revenue types: CPM, CPC, CPCV, CPI, CPA
+    pub revenue_type: Option<RevenueType>,
+
+    /// The basis of calculation for the revenue_type
+    pub revenue_amount: Option<f64>,
+
+    /// ID of a Bid Modifier object to associate with each Campaign
+    pub bid_modifier_id: Option<u64>,
+
+    /// ID of the Delivery Modifier to associate with each Campaign
+    pub delivery_modifier_id: Option<u64>,
+
+    /// Maximum bid after taking into consideration any Bid Modifiers
+    pub max_bid: Option<f64>,
+
+    /// Frequency cap JSON
+    pub frequency_cap: Option<Vec<FrequencyCap>>,
+
+    /// The method of frequency capping
+    pub frequency_cap_type: Option<FrequencyCapType>,
+
+    /// Continents in which each Campaign is eligible to serve
+    pub continents: Option<Vec<Continent>>,
+
+    /// Currency in which each Campaign will bid
+    pub currency: Option<Currency>,
+
+    /// Notes about the Campaign Preset, up to 255 chars
+    pub notes: Option<String>,
+
+    /// Is the Campaign Preset active?
+    pub active: Option<bool>,
+
+    // Undocumented
+    pub account_id: Option<u64>,
+    pub create_date: Option<String>,
+    pub update_date: Option<String>,
+}
+
+impl CampaignPreset {
+    /// Create a builder for CreateCampaignPreset
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::{common::BudgetType, CampaignPreset};
+    ///
+    /// let create_campaign_preset = CampaignPreset::create_builder()
+    ///     .campaign_preset_name("House defaults")
+    ///     .budget_type(BudgetType::Spend)
+    ///     .build();
+    ///
+    /// let campaign_preset = beeswax_client.create(&create_campaign_preset).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn create_builder() -> CreateCampaignPresetBuilder<(
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+    )> {
+        CreateCampaignPreset::builder()
+    }
+
+    /// Create a builder for ReadCampaignPreset
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::CampaignPreset;
+    ///
+    /// let read_campaign_preset = CampaignPreset::read_builder()
+    ///     .campaign_preset_name("House defaults".to_string())
+    ///     .build();
+    ///
+    /// let campaign_presets = beeswax_client.read(&read_campaign_preset).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadCampaignPresetBuilder<((), (), (), ())> {
+        ReadCampaignPreset::builder()
+    }
+
+    /// Create a builder for DeleteCampaignPreset
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::CampaignPreset;
+    ///
+    /// let delete_campaign_preset = CampaignPreset::delete_builder()
+    ///     .campaign_preset_id(10)
+    ///     .build();
+    ///
+    /// beeswax_client.delete(&delete_campaign_preset).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_builder() -> DeleteCampaignPresetBuilder<((),)> {
+        DeleteCampaignPreset::builder()
+    }
+
+    /// Start a Campaign for the Advertiser with the preset's values filled in. The fields Buzz
+    /// requires are always given, so the preset's campaign_budget is not used. Any field can be
+    /// changed before creating it.
+    /// ```
+    /// use beeswax::resource::{common::Currency, CampaignPreset};
+    ///
+    /// let campaign_preset = CampaignPreset {
+    ///     currency: Some(Currency::Euro),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let create_campaign = campaign_preset.create_campaign(
+    ///     1,
+    ///     "Winter lead generation",
+    ///     1000.0,
+    ///     "2020-01-01 00:00:00",
+    /// );
+    /// assert_eq!(create_campaign.currency, Some(Currency::Euro));
+    /// ```
+    pub fn create_campaign<N: Into<String>, D: Into<String>>(
+        &self,
+        advertiser_id: u64,
+        campaign_name: N,
+        campaign_budget: f64,
+        start_date: D,
+    ) -> CreateCampaign {
+        CreateCampaign {
+            advertiser_id,
+            campaign_name: campaign_name.into(),
+            campaign_budget,
+            start_date: start_date.into(),
+            daily_budget: self.daily_budget,
+            budget_type: self.budget_type.clone(),
+            revenue_type: self.revenue_type.clone(),
+            revenue_amount: self.revenue_amount,
+            bid_modifier_id: self.bid_modifier_id,
+            delivery_modifier_id: self.delivery_modifier_id,
+            max_bid: self.max_bid,
+            frequency_cap: self.frequency_cap.clone(),
+            frequency_cap_type: self.frequency_cap_type.clone(),
+            continents: self.continents.clone(),
+            currency: self.currency.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+impl Resource for CampaignPreset {
+    const NAME: &'static str = "campaign_preset";
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadCampaignPreset {
+    /// Unique ID of the Campaign Preset
+    #[builder(default, setter(into))]
+    pub campaign_preset_id: Option<u64>,
+    /// Name of the Campaign Preset. Supports %LIKE% syntax
+    #[builder(default, setter(into))]
+    pub campaign_preset_name: Option<String>,
+    /// The Advertiser the preset may be used by
+    #[builder(default, setter(into))]
+    pub advertiser_id: Option<u64>,
+    /// Is the Campaign Preset active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Read<CampaignPreset> for ReadCampaignPreset {}
+
+impl PartialEq<CampaignPreset> for ReadCampaignPreset {
+    fn eq(&self, other: &CampaignPreset) -> bool {
+        (self.campaign_preset_id.is_none()
+            || self.campaign_preset_id == Some(other.campaign_preset_id))
+            && (self.campaign_preset_name.is_none()
+                || self.campaign_preset_name.as_ref() == Some(&other.campaign_preset_name))
+            && (self.advertiser_id.is_none() || self.advertiser_id == other.advertiser_id)
+            && (self.active.is_none() || self.active == other.active)
+    }
+}
+
+impl ReadCampaignPreset {
+    /// Search criteria for the Advertiser's default Campaign Preset, if it has one
+    pub fn advertiser_default(advertiser: &Advertiser) -> Option<ReadCampaignPreset> {
+        advertiser
+            .default_campaign_preset_id
+            .map(|campaign_preset_id| ReadCampaignPreset {
+                campaign_preset_id: Some(campaign_preset_id),
+                ..Default::default()
+            })
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct CreateCampaignPreset {
+    /// Name of the Campaign Preset, e.g. "House defaults"
+    #[builder(setter(into))]
+    pub campaign_preset_name: String,
+
+    /// The Advertiser the preset may be used by. If empty, every Advertiser may use the preset.
+    #[builder(default, setter(into))]
+    pub advertiser_id: Option<u64>,
+
+    /// Maximum amount to spend on each Campaign
+    #[builder(default, setter(into))]
+    pub campaign_budget: Option<f64>,
+
+    /// Maximum amount to spend or deliver in a day
+    #[builder(default, setter(into))]
+    pub daily_budget: Option<f64>,
+
+    /// Type of budget, 0=spend, 1=impressions, 2=spend with vendor fees
+    #[builder(default, setter(into))]
+    pub budget_type: Option<BudgetType>,
+
+    /// Supported revenue types: CPM, CPC, CPCV, CPI, CPA
+    #[builder(default, setter(into))]
+    pub revenue_type: Option<RevenueType>,
+
+    /// The basis of calculation for the revenue_type
+    #[builder(default, setter(into))]
+    pub revenue_amount: Option<f64>,
+
+    /// ID of a Bid Modifier object to associate with each Campaign
+    #[builder(default, setter(into))]
+    pub bid_modifier_id: Option<u64>,
+
+    /// ID of the Delivery Modifier to associate with each Campaign
+    #[builder(default, setter(into))]
+    pub delivery_modifier_id: Option<u64>,
+
+    /// Maximum bid after taking into consideration any Bid Modifiers
+    #[builder(default, setter(into))]
+    pub max_bid: Option<f64>,
+
+    /// Frequency cap JSON
+    #[builder(default, setter(into))]
+    pub frequency_cap: Option<Vec<FrequencyCap>>,
+
+    /// The method of frequency capping
+    #[builder(default, setter(into))]
+    pub frequency_cap_type: Option<FrequencyCapType>,
+
+    /// Continents in which each Campaign is eligible to serve
+    #[builder(default, setter(into))]
+    pub continents: Option<Vec<Continent>>,
+
+    /// Currency in which each Campaign will bid
+    #[builder(default, setter(into))]
+    pub currency: Option<Currency>,
+
+    /// Notes about the Campaign Preset, up to 255 chars
+    #[builder(default, setter(into))]
+    pub notes: Option<String>,
+
+    /// Is the Campaign Preset active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Create<CampaignPreset> for CreateCampaignPreset {
+    fn into_resource(self, campaign_preset_id: u64) -> CampaignPreset {
+        CampaignPreset {
+            campaign_preset_id,
+            campaign_preset_name: self.campaign_preset_name,
+            advertiser_id: self.advertiser_id,
+            campaign_budget: self.campaign_budget,
+            daily_budget: self.daily_budget,
+            budget_type: self.budget_type,
+            revenue_type: self.revenue_type,
+            revenue_amount: self.revenue_amount,
+            bid_modifier_id: self.bid_modifier_id,
+            delivery_modifier_id: self.delivery_modifier_id,
+            max_bid: self.max_bid,
+            frequency_cap: self.frequency_cap,
+            frequency_cap_type: self.frequency_cap_type,
+            continents: self.continents,
+            currency: self.currency,
+            notes: self.notes,
+            active: self.active,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TypedBuilder)]
+pub struct DeleteCampaignPreset {
+    campaign_preset_id: u64,
+}
+
+impl Delete<CampaignPreset> for DeleteCampaignPreset {}
+
+impl Delete<CampaignPreset> for CampaignPreset {}
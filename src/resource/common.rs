@@ -5,9 +5,27 @@ use std::collections::HashMap;
 #[derive(Clone, Debug, Serialize_repr, Deserialize_repr, PartialEq)]
 #[repr(u8)]
 pub enum ConversionMethod {
+    /// Credit the last click before the conversion. At this time this is the only method Buzz
+    /// supports.
     LastClick = 1,
 }
 
+impl ConversionMethod {
+    /// Does the method credit conversions to clicks?
+    pub fn counts_clicks(&self) -> bool {
+        match self {
+            ConversionMethod::LastClick => true,
+        }
+    }
+
+    /// Does the method credit conversions to impressions without a click?
+    pub fn counts_views(&self) -> bool {
+        match self {
+            ConversionMethod::LastClick => false,
+        }
+    }
+}
+
 impl Default for ConversionMethod {
     fn default() -> Self {
         ConversionMethod::LastClick
@@ -111,7 +129,9 @@ pub struct BiddingStratergy {
     pacing: Option<String>,
 }
 
-#[derive(Clone, Serialize)]
+/// The Views built into Buzz, plus Custom for Views an administrator has created for the account.
+/// Names that match a built-in View always deserialize to that View rather than Custom.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
 pub enum ViewName {
     /// Categorization for advertisers (e.g. automotive)
     #[serde(rename = "advertiser_category")]
@@ -244,6 +264,56 @@ pub enum ViewName {
     /// Types of video protocols supported
     #[serde(rename = "video_protocols")]
     VideoProtocols,
+    /// A View created by the Buzz administrator, by name
+    #[serde(untagged)]
+    Custom(String),
+}
+
+/// Look up a View by name, using the built-in View of that name if there is one
+/// ```
+/// use beeswax::resource::common::ViewName;
+///
+/// assert_eq!(ViewName::from("countries"), ViewName::Countries);
+/// assert_eq!(
+///     ViewName::from("account_brands"),
+///     ViewName::Custom("account_brands".to_string())
+/// );
+/// ```
+impl From<&str> for ViewName {
+    fn from(view_name: &str) -> Self {
+        ViewName::from(view_name.to_string())
+    }
+}
+
+impl From<String> for ViewName {
+    fn from(view_name: String) -> Self {
+        serde_json::from_value(serde_json::Value::String(view_name.clone()))
+            .unwrap_or(ViewName::Custom(view_name))
+    }
+}
+
+/// The kinds of tag Buzz can generate for Events and Segments, as found in the event_tag_types
+/// view
+#[derive(Copy, Clone, Debug, Default, Serialize_repr, Deserialize_repr, PartialEq)]
+#[repr(u8)]
+pub enum EventTagType {
+    /// An image pixel, <img src="...">
+    #[default]
+    ImagePixel = 1,
+    /// A JavaScript tag, <script src="...">
+    JavaScript = 2,
+    /// An iframe tag, <iframe src="...">
+    Iframe = 3,
+}
+
+/// The order to sort results in when reading resources
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    /// Smallest first
+    Asc,
+    /// Largest first
+    Desc,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
@@ -289,3 +359,37 @@ pub enum Currency {
     #[serde(rename = "USD")]
     UnitedStatesDollar,
 }
+
+/// The value a modifier term matches against. Values may be integers, decimals or strings depending
+/// on the field.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(untagged)]
+pub enum TermValue {
+    Integer(i64),
+    Decimal(f64),
+    Text(String),
+}
+
+impl From<i64> for TermValue {
+    fn from(value: i64) -> Self {
+        TermValue::Integer(value)
+    }
+}
+
+impl From<f64> for TermValue {
+    fn from(value: f64) -> Self {
+        TermValue::Decimal(value)
+    }
+}
+
+impl From<&str> for TermValue {
+    fn from(value: &str) -> Self {
+        TermValue::Text(value.to_string())
+    }
+}
+
+impl From<String> for TermValue {
+    fn from(value: String) -> Self {
+        TermValue::Text(value)
+    }
+}
@@ -90,9 +90,13 @@ pub struct Creative {
     /// List of objects to track VAST video progress
     pub progress_events: Option<JsonValue>,
 
-    /// List of CreativeAddOn IDs to add to the Creative
+    /// List of CreativeAddon IDs to add to the Creative
     pub creative_addons: Option<Vec<u64>>,
 
+    /// List of URLs of JavaScript files to load with the Creative. Scripts shared by many Creatives
+    /// are better managed as a CreativeAddon.
+    pub scripts: Option<Vec<String>>,
+
     /// URL to an image thumbnail for the creative. This field will be automatically set if you
     /// associate the creative with a Creative Asset that has a valid thumbnail but must be updated
     /// manually when using a tag-based Creative. Thumbnail is required by some exchanges to serve.
@@ -113,7 +117,7 @@ pub struct Creative {
     /// Is the Creative active?
     pub active: Option<bool>,
     // Undocumented
-    pub creative_status_id: Option<bool>,
+    pub creative_status_id: Option<u64>,
     pub creative_attributes: JsonValue,
     // ToDo: Unknown type,
     // pub creative_assets: Unknown,
@@ -121,8 +125,6 @@ pub struct Creative {
     pub preview_token: Option<String>,
     // ToDo: Unknown type,
     // pub frequency_cap: Unknown,
-    // ToDo: Unknown type,
-    // pub scripts: Unknown,
     pub push_status: Option<u64>,
     pub push_update: Option<bool>,
     pub account_id: Option<u64>,
@@ -177,6 +179,7 @@ impl Creative {
         (),
         (),
         (),
+        (),
     )> {
         CreateCreative::builder()
     }
@@ -222,6 +225,30 @@ impl Creative {
     pub fn delete_builder() -> DeleteCreativeBuilder<((),)> {
         DeleteCreative::builder()
     }
+
+    /// Does the Creative fire the pixel?
+    pub fn has_pixel(&self, pixel: &str) -> bool {
+        match &self.pixels {
+            Some(pixels) => pixels.iter().any(|p| p == pixel),
+            None => false,
+        }
+    }
+
+    /// Add the pixel URL to the Creative, if it is not already. Update the Creative to save the
+    /// change. Pixels shared by many Creatives are better managed as a CreativeAddon.
+    pub fn add_pixel<P: Into<String>>(&mut self, pixel: P) {
+        let pixel = pixel.into();
+        if !self.has_pixel(&pixel) {
+            self.pixels.get_or_insert_with(Vec::new).push(pixel);
+        }
+    }
+
+    /// Remove the pixel URL from the Creative. Update the Creative to save the change.
+    pub fn remove_pixel(&mut self, pixel: &str) {
+        if let Some(pixels) = self.pixels.as_mut() {
+            pixels.retain(|p| p != pixel);
+        }
+    }
 }
 
 impl Resource for Creative {
@@ -380,10 +407,15 @@ pub struct CreateCreative {
     #[builder(default, setter(into))]
     pub progress_events: Option<JsonValue>,
 
-    /// List of CreativeAddOn IDs to add to the Creative
+    /// List of CreativeAddon IDs to add to the Creative
     #[builder(default, setter(into))]
     pub creative_addons: Option<Vec<u64>>,
 
+    /// List of URLs of JavaScript files to load with the Creative. Scripts shared by many Creatives
+    /// are better managed as a CreativeAddon.
+    #[builder(default, setter(into))]
+    pub scripts: Option<Vec<String>>,
+
     /// URL to an image thumbnail for the creative. This field will be automatically set if you
     /// associate the creative with a Creative Asset that has a valid thumbnail but must be updated
     /// manually when using a tag-based Creative. Thumbnail is required by some exchanges to serve.
@@ -437,6 +469,7 @@ impl Create<Creative> for CreateCreative {
             events: self.events,
             progress_events: self.progress_events,
             creative_addons: self.creative_addons,
+            scripts: self.scripts,
             creative_thumbnail_url: self.creative_thumbnail_url,
             start_date: self.start_date,
             end_date: self.end_date,
@@ -0,0 +1,261 @@
+//! A Creative Add-On is a script, pixel or verification tag that is served alongside Creatives,
+//! such as a viewability vendor's measurement script. Add-ons are attached to Creatives by adding
+//! their IDs to creative_addons, so a vendor's tag can be changed in one place for every Creative
+//! using it.
+
+use crate::resource::{creative::Creative, Create, Delete, Read, Resource};
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+/// What a Creative Add-On's content is
+#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum CreativeAddonType {
+    /// URL of a JavaScript file loaded with the Creative
+    #[default]
+    Script,
+    /// URL of an image pixel fired when the Creative is served
+    Pixel,
+    /// Markup of a third party verification tag, e.g. for viewability or brand safety measurement
+    Verification,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct CreativeAddon {
+    /// Unique ID of the Creative Add-On
+    pub creative_addon_id: u64,
+
+    /// Name of the Creative Add-On, e.g. "Viewability measurement"
+    pub creative_addon_name: String,
+
+    /// What the content of the Creative Add-On is
+    pub creative_addon_type: CreativeAddonType,
+
+    /// The script URL, pixel URL or tag markup, depending on the creative_addon_type
+    pub creative_addon_content: String,
+
+    /// Name of the vendor providing the add-on, e.g. "Example Verification"
+    pub vendor: Option<String>,
+
+    /// The Advertiser the add-on may be used by. If empty, every Advertiser may use the add-on.
+    pub advertiser_id: Option<u64>,
+
+    /// Does the content use https?
+    pub secure: Option<bool>,
+
+    /// An alternative id to lookup the Creative Add-On, if desired
+    pub alternative_id: Option<String>,
+
+    /// Notes about the Creative Add-On, up to 255 chars
+    pub notes: Option<String>,
+
+    /// Is the Creative Add-On active?
+    pub active: Option<bool>,
+
+    // Undocumented
+    pub account_id: Option<u64>,
+    pub create_date: Option<String>,
+    pub update_date: Option<String>,
+}
+
+impl CreativeAddon {
+    /// Create a builder for CreateCreativeAddon
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::{creative_addon::CreativeAddonType, CreativeAddon};
+    ///
+    /// let create_creative_addon = CreativeAddon::create_builder()
+    ///     .creative_addon_name("Viewability measurement")
+    ///     .creative_addon_type(CreativeAddonType::Script)
+    ///     .creative_addon_content("https://verify.example.com/measure.js")
+    ///     .build();
+    ///
+    /// let creative_addon = beeswax_client.create(&create_creative_addon).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn create_builder() -> CreateCreativeAddonBuilder<((), (), (), (), (), (), (), (), ())> {
+        CreateCreativeAddon::builder()
+    }
+
+    /// Create a builder for ReadCreativeAddon
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::CreativeAddon;
+    ///
+    /// let read_creative_addon = CreativeAddon::read_builder()
+    ///     .vendor("Example Verification".to_string())
+    ///     .build();
+    ///
+    /// let creative_addons = beeswax_client.read(&read_creative_addon).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadCreativeAddonBuilder<((), (), (), (), (), ())> {
+        ReadCreativeAddon::builder()
+    }
+
+    /// Create a builder for DeleteCreativeAddon
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::CreativeAddon;
+    ///
+    /// let delete_creative_addon = CreativeAddon::delete_builder()
+    ///     .creative_addon_id(10)
+    ///     .build();
+    ///
+    /// beeswax_client.delete(&delete_creative_addon).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_builder() -> DeleteCreativeAddonBuilder<((),)> {
+        DeleteCreativeAddon::builder()
+    }
+
+    /// Is the add-on attached to the Creative?
+    pub fn is_attached(&self, creative: &Creative) -> bool {
+        match &creative.creative_addons {
+            Some(creative_addons) => creative_addons.contains(&self.creative_addon_id),
+            None => false,
+        }
+    }
+
+    /// Attach the add-on to the Creative, if it is not already. Update the Creative to save the
+    /// change.
+    pub fn attach(&self, creative: &mut Creative) {
+        if !self.is_attached(creative) {
+            creative
+                .creative_addons
+                .get_or_insert_with(Vec::new)
+                .push(self.creative_addon_id);
+        }
+    }
+
+    /// Detach the add-on from the Creative. Update the Creative to save the change.
+    pub fn detach(&self, creative: &mut Creative) {
+        if let Some(creative_addons) = creative.creative_addons.as_mut() {
+            creative_addons
+                .retain(|creative_addon_id| *creative_addon_id != self.creative_addon_id);
+        }
+    }
+}
+
+impl Resource for CreativeAddon {
+    const NAME: &'static str = "creative_addon";
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadCreativeAddon {
+    /// Unique ID of the Creative Add-On
+    #[builder(default, setter(into))]
+    pub creative_addon_id: Option<u64>,
+    /// Name of the Creative Add-On. Supports %LIKE% syntax
+    #[builder(default, setter(into))]
+    pub creative_addon_name: Option<String>,
+    /// What the content of the Creative Add-On is
+    #[builder(default, setter(into))]
+    pub creative_addon_type: Option<CreativeAddonType>,
+    /// Name of the vendor providing the add-on
+    #[builder(default, setter(into))]
+    pub vendor: Option<String>,
+    /// The Advertiser the add-on may be used by
+    #[builder(default, setter(into))]
+    pub advertiser_id: Option<u64>,
+    /// Is the Creative Add-On active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Read<CreativeAddon> for ReadCreativeAddon {}
+
+impl PartialEq<CreativeAddon> for ReadCreativeAddon {
+    fn eq(&self, other: &CreativeAddon) -> bool {
+        (self.creative_addon_id.is_none()
+            || self.creative_addon_id == Some(other.creative_addon_id))
+            && (self.creative_addon_name.is_none()
+                || self.creative_addon_name.as_ref() == Some(&other.creative_addon_name))
+            && (self.creative_addon_type.is_none()
+                || self.creative_addon_type == Some(other.creative_addon_type))
+            && (self.vendor.is_none() || self.vendor == other.vendor)
+            && (self.advertiser_id.is_none() || self.advertiser_id == other.advertiser_id)
+            && (self.active.is_none() || self.active == other.active)
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct CreateCreativeAddon {
+    /// Name of the Creative Add-On, e.g. "Viewability measurement"
+    #[builder(setter(into))]
+    pub creative_addon_name: String,
+
+    /// What the content of the Creative Add-On is
+    pub creative_addon_type: CreativeAddonType,
+
+    /// The script URL, pixel URL or tag markup, depending on the creative_addon_type
+    #[builder(setter(into))]
+    pub creative_addon_content: String,
+
+    /// Name of the vendor providing the add-on, e.g. "Example Verification"
+    #[builder(default, setter(into))]
+    pub vendor: Option<String>,
+
+    /// The Advertiser the add-on may be used by. If empty, every Advertiser may use the add-on.
+    #[builder(default, setter(into))]
+    pub advertiser_id: Option<u64>,
+
+    /// Does the content use https?
+    #[builder(default, setter(into))]
+    pub secure: Option<bool>,
+
+    /// An alternative id to lookup the Creative Add-On, if desired
+    #[builder(default, setter(into))]
+    pub alternative_id: Option<String>,
+
+    /// Notes about the Creative Add-On, up to 255 chars
+    #[builder(default, setter(into))]
+    pub notes: Option<String>,
+
+    /// Is the Creative Add-On active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Create<CreativeAddon> for CreateCreativeAddon {
+    fn into_resource(self, creative_addon_id: u64) -> CreativeAddon {
+        CreativeAddon {
+            creative_addon_id,
+            creative_addon_name: self.creative_addon_name,
+            creative_addon_type: self.creative_addon_type,
+            creative_addon_content: self.creative_addon_content,
+            vendor: self.vendor,
+            advertiser_id: self.advertiser_id,
+            secure: self.secure,
+            alternative_id: self.alternative_id,
+            notes: self.notes,
+            active: self.active,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TypedBuilder)]
+pub struct DeleteCreativeAddon {
+    creative_addon_id: u64,
+}
+
+impl Delete<CreativeAddon> for DeleteCreativeAddon {}
+
+impl Delete<CreativeAddon> for CreativeAddon {}
@@ -0,0 +1,291 @@
+//! A Creative Approval records the audit of a Creative by one exchange or vendor. Some exchanges
+//! only serve Creatives they have approved, so a Creative is submitted for audit by creating a
+//! Creative Approval for each vendor, which stays pending until the vendor approves or rejects it.
+//! The vendors auditing Creatives are found in the creative_approval_vendors view.
+
+use crate::client::{async_client::impl_async_clients, sync_client::SyncClient, PollOptions};
+use crate::resource::{creative::Creative, Create, Read, Resource};
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+/// Where a Creative is in a vendor's audit
+#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ApprovalStatus {
+    /// Submitted and waiting for the vendor
+    #[default]
+    Pending,
+    /// The vendor will serve the Creative
+    Approved,
+    /// The vendor will not serve the Creative, see rejection_reasons
+    Rejected,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct CreativeApproval {
+    /// Unique ID of the Creative Approval
+    pub creative_approval_id: u64,
+
+    /// The Creative being audited
+    pub creative_id: u64,
+
+    /// Key of the exchange or vendor auditing the Creative, from the creative_approval_vendors
+    /// view
+    pub vendor: String,
+
+    /// Where the Creative is in the vendor's audit
+    pub approval_status: ApprovalStatus,
+
+    /// Why the vendor rejected the Creative, e.g. "Landing page does not load"
+    pub rejection_reasons: Option<Vec<String>>,
+
+    /// When the vendor last changed the approval status
+    pub audit_date: Option<String>,
+
+    // Undocumented
+    pub account_id: Option<u64>,
+    pub create_date: Option<String>,
+    pub update_date: Option<String>,
+}
+
+impl CreativeApproval {
+    /// Create a builder for CreateCreativeApproval, which submits a Creative for audit
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::CreativeApproval;
+    ///
+    /// let create_creative_approval = CreativeApproval::create_builder()
+    ///     .creative_id(10)
+    ///     .vendor("google")
+    ///     .build();
+    ///
+    /// let creative_approval = beeswax_client.create(&create_creative_approval).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_builder() -> CreateCreativeApprovalBuilder<((), ())> {
+        CreateCreativeApproval::builder()
+    }
+
+    /// Create a builder for ReadCreativeApproval
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::{creative_approval::ApprovalStatus, CreativeApproval};
+    ///
+    /// let read_creative_approval = CreativeApproval::read_builder()
+    ///     .approval_status(ApprovalStatus::Rejected)
+    ///     .build();
+    ///
+    /// let creative_approvals = beeswax_client.read(&read_creative_approval).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadCreativeApprovalBuilder<((), (), (), ())> {
+        ReadCreativeApproval::builder()
+    }
+
+    /// Is the Creative still waiting for the vendor?
+    pub fn is_pending(&self) -> bool {
+        self.approval_status == ApprovalStatus::Pending
+    }
+}
+
+impl Resource for CreativeApproval {
+    const NAME: &'static str = "creative_approval";
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadCreativeApproval {
+    /// Unique ID of the Creative Approval
+    #[builder(default, setter(into))]
+    pub creative_approval_id: Option<u64>,
+    /// The Creative being audited
+    #[builder(default, setter(into))]
+    pub creative_id: Option<u64>,
+    /// Key of the exchange or vendor auditing the Creative
+    #[builder(default, setter(into))]
+    pub vendor: Option<String>,
+    /// Where the Creative is in the vendor's audit
+    #[builder(default, setter(into))]
+    pub approval_status: Option<ApprovalStatus>,
+}
+
+impl Read<CreativeApproval> for ReadCreativeApproval {}
+
+impl PartialEq<CreativeApproval> for ReadCreativeApproval {
+    fn eq(&self, other: &CreativeApproval) -> bool {
+        (self.creative_approval_id.is_none()
+            || self.creative_approval_id == Some(other.creative_approval_id))
+            && (self.creative_id.is_none() || self.creative_id == Some(other.creative_id))
+            && (self.vendor.is_none() || self.vendor.as_ref() == Some(&other.vendor))
+            && (self.approval_status.is_none()
+                || self.approval_status == Some(other.approval_status))
+    }
+}
+
+/// Submit a Creative for audit by a vendor
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct CreateCreativeApproval {
+    /// The Creative to audit
+    pub creative_id: u64,
+
+    /// Key of the exchange or vendor to audit the Creative, from the creative_approval_vendors
+    /// view
+    #[builder(setter(into))]
+    pub vendor: String,
+}
+
+impl Create<CreativeApproval> for CreateCreativeApproval {
+    fn into_resource(self, creative_approval_id: u64) -> CreativeApproval {
+        CreativeApproval {
+            creative_approval_id,
+            creative_id: self.creative_id,
+            vendor: self.vendor,
+            approval_status: ApprovalStatus::Pending,
+            ..Default::default()
+        }
+    }
+}
+
+impl Creative {
+    /// Submit the Creative for audit by each of the vendors
+    pub fn submit_for_audit_sync<C: SyncClient>(
+        &self,
+        beeswax_client: &C,
+        vendors: &[&str],
+    ) -> Result<Vec<CreativeApproval>> {
+        vendors
+            .iter()
+            .map(|vendor| audit(self.creative_id, vendor).create_sync(beeswax_client))
+            .collect()
+    }
+
+    /// The approval of the Creative by each vendor it was submitted to
+    pub fn approvals_sync<C: SyncClient>(
+        &self,
+        beeswax_client: &C,
+    ) -> Result<Vec<CreativeApproval>> {
+        ReadCreativeApproval::from(self).read_sync(beeswax_client)
+    }
+
+    /// Poll Buzz until every vendor the Creative was submitted to has approved or rejected it,
+    /// returning the approvals. Rejected approvals hold the vendor's rejection_reasons.
+    /// A Creative that hasn't been submitted to any vendor is never decided, so times out.
+    pub fn wait_for_approval_sync<C: SyncClient>(
+        &self,
+        beeswax_client: &C,
+        poll: PollOptions,
+    ) -> Result<Vec<CreativeApproval>> {
+        let creative_approvals = poll.poll_sync(|| {
+            let creative_approvals = self.approvals_sync(beeswax_client)?;
+            Ok(decided(creative_approvals))
+        })?;
+        creative_approvals.ok_or_else(|| approval_timeout(self.creative_id, poll))
+    }
+}
+
+impl From<&Creative> for ReadCreativeApproval {
+    fn from(creative: &Creative) -> Self {
+        ReadCreativeApproval {
+            creative_id: Some(creative.creative_id),
+            ..Default::default()
+        }
+    }
+}
+
+fn audit(creative_id: u64, vendor: &str) -> CreateCreativeApproval {
+    CreateCreativeApproval {
+        creative_id,
+        vendor: vendor.to_string(),
+    }
+}
+
+/// The approvals, once there are some and none of them are pending
+fn decided(creative_approvals: Vec<CreativeApproval>) -> Option<Vec<CreativeApproval>> {
+    Some(creative_approvals).filter(|creative_approvals| {
+        !creative_approvals.is_empty()
+            && !creative_approvals
+                .iter()
+                .any(|creative_approval| creative_approval.is_pending())
+    })
+}
+
+fn approval_timeout(creative_id: u64, poll: PollOptions) -> Box<dyn std::error::Error> {
+    format!(
+        "creative {} was still pending approval after {} attempts",
+        creative_id, poll.max_attempts
+    )
+    .into()
+}
+
+impl_async_clients! {
+    /// Submit the Creative for audit by each of the vendors
+    pub async fn submit_for_audit(
+        &self,
+        creative: &Creative,
+        vendors: &[&str],
+    ) -> Result<Vec<CreativeApproval>> {
+        let mut creative_approvals = Vec::new();
+        for vendor in vendors {
+            creative_approvals.push(self.create(&audit(creative.creative_id, vendor)).await?);
+        }
+        Ok(creative_approvals)
+    }
+
+    /// Poll until every vendor the Creative was submitted to has approved or rejected it,
+    /// returning the approvals. Rejected approvals hold the vendor's rejection_reasons.
+    /// A Creative that hasn't been submitted to any vendor is never decided, so times out.
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::client::PollOptions;
+    /// use beeswax::resource::{
+    ///     creative_approval::{ApprovalStatus, CreativeApproval},
+    ///     Creative,
+    /// };
+    ///
+    /// let creative = Creative {
+    ///     creative_id: 10,
+    ///     ..Default::default()
+    /// };
+    /// beeswax_client.insert(CreativeApproval {
+    ///     creative_approval_id: 1,
+    ///     creative_id: 10,
+    ///     vendor: "google".to_string(),
+    ///     approval_status: ApprovalStatus::Rejected,
+    ///     rejection_reasons: Some(vec!["Landing page does not load".to_string()]),
+    ///     ..Default::default()
+    /// });
+    ///
+    /// let creative_approvals = beeswax_client
+    ///     .wait_for_approval(&creative, PollOptions::default())
+    ///     .await?;
+    /// assert_eq!(creative_approvals[0].approval_status, ApprovalStatus::Rejected);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_approval(
+        &self,
+        creative: &Creative,
+        poll: PollOptions,
+    ) -> Result<Vec<CreativeApproval>> {
+        let read_creative_approval = ReadCreativeApproval::from(creative);
+        let read_creative_approval = &read_creative_approval;
+        let creative_approvals = poll
+            .poll_async(|| async move { Ok(decided(self.read(read_creative_approval).await?)) })
+            .await?;
+        creative_approvals.ok_or_else(|| approval_timeout(creative.creative_id, poll))
+    }
+}
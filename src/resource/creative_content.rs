@@ -0,0 +1,260 @@
+//! Typed models of the creative_content, events and progress_events JSON carried by Creatives. The
+//! shapes here match the fields of Buzz's global Creative Templates for each creative type, and can
+//! be converted to and from the JsonValue fields on Creative and CreateCreative.
+
+use crate::resource::{
+    common::CreativeType,
+    creative::{CreateCreative, Creative},
+};
+use crate::Result;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::convert::TryFrom;
+
+/// Content of a Banner Creative, either a third party tag or raw HTML
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub struct BannerContent {
+    /// Third party ad tag, e.g. a DoubleClick script tag
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    /// HTML to render as the Creative
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html: Option<String>,
+    /// ID of the image Creative Asset to render, when not using a tag or HTML
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<u64>,
+}
+
+/// Content of a Video Creative, either a hosted VAST document or VAST XML
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub struct VideoContent {
+    /// URL of a third party VAST document to wrap
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vast_tag_url: Option<String>,
+    /// VAST XML to serve inline
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vast_xml: Option<String>,
+    /// Duration of the video in seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<u64>,
+    /// Offset in seconds after which the video may be skipped, if skippable
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_offset: Option<u64>,
+}
+
+/// Content of a Native Creative
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub struct NativeContent {
+    /// Headline of the ad
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Body text of the ad
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    /// ID of the main image Creative Asset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<u64>,
+    /// ID of the icon or logo Creative Asset
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<u64>,
+    /// Call to action text, e.g. "Install now"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub call_to_action: Option<String>,
+    /// Name of the brand sponsoring the ad
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sponsored_by: Option<String>,
+    /// App store rating, between 0 and 5
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rating: Option<f64>,
+}
+
+/// The creative_content of a Creative, typed by the Creative's creative_type
+#[derive(Clone, Debug, PartialEq)]
+pub enum CreativeContent {
+    Banner(BannerContent),
+    Video(VideoContent),
+    Native(NativeContent),
+}
+
+impl CreativeContent {
+    /// Read creative_content JSON as the content of the given creative type
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use beeswax::resource::{
+    ///     common::CreativeType,
+    ///     creative_content::{CreativeContent, NativeContent},
+    /// };
+    /// use serde_json::json;
+    ///
+    /// let content = CreativeContent::from_json(
+    ///     CreativeType::Native,
+    ///     &json!({ "TITLE": "Winter sale", "CALL_TO_ACTION": "Shop now" }),
+    /// )?;
+    ///
+    /// assert_eq!(
+    ///     content,
+    ///     CreativeContent::Native(NativeContent {
+    ///         title: Some("Winter sale".to_string()),
+    ///         call_to_action: Some("Shop now".to_string()),
+    ///         ..Default::default()
+    ///     })
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_json(creative_type: CreativeType, creative_content: &JsonValue) -> Result<Self> {
+        Ok(match creative_type {
+            CreativeType::Banner => CreativeContent::Banner(from_json(creative_content)?),
+            CreativeType::Video => CreativeContent::Video(from_json(creative_content)?),
+            CreativeType::Native => CreativeContent::Native(from_json(creative_content)?),
+        })
+    }
+
+    /// The creative type this content can be used with
+    pub fn creative_type(&self) -> CreativeType {
+        match self {
+            CreativeContent::Banner(_) => CreativeType::Banner,
+            CreativeContent::Video(_) => CreativeType::Video,
+            CreativeContent::Native(_) => CreativeType::Native,
+        }
+    }
+}
+
+impl From<CreativeContent> for JsonValue {
+    fn from(creative_content: CreativeContent) -> Self {
+        match creative_content {
+            CreativeContent::Banner(content) => to_json(&content),
+            CreativeContent::Video(content) => to_json(&content),
+            CreativeContent::Native(content) => to_json(&content),
+        }
+    }
+}
+
+macro_rules! json_conversions {
+    ( $( $i:ident ),* ) => {
+        $(
+            impl From<$i> for JsonValue {
+                fn from(content: $i) -> Self {
+                    to_json(&content)
+                }
+            }
+
+            impl TryFrom<&JsonValue> for $i {
+                type Error = serde_json::Error;
+
+                fn try_from(value: &JsonValue) -> std::result::Result<Self, Self::Error> {
+                    $i::deserialize(value)
+                }
+            }
+        )*
+    };
+}
+
+json_conversions!(BannerContent, VideoContent, NativeContent);
+
+/// VAST tracking events that third party pixels can be attached to
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub enum VastEvent {
+    CreativeView,
+    Start,
+    FirstQuartile,
+    Midpoint,
+    ThirdQuartile,
+    Complete,
+    Mute,
+    Unmute,
+    Pause,
+    Resume,
+    Rewind,
+    Skip,
+    Fullscreen,
+    ExitFullscreen,
+    Close,
+}
+
+/// A third party pixel fired when a VAST event occurs
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct TrackingEvent {
+    /// The event that fires the pixel
+    pub event: VastEvent,
+    /// URL of the pixel
+    pub url: String,
+}
+
+/// A third party pixel fired once the video has played to a given point
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct ProgressEvent {
+    /// How far into the video to fire the pixel, either a time ("00:00:05") or a percentage
+    /// ("25%")
+    pub offset: String,
+    /// URL of the pixel
+    pub url: String,
+}
+
+fn to_json<T: Serialize>(value: &T) -> JsonValue {
+    serde_json::to_value(value).expect("creative content always serializes to JSON")
+}
+
+fn from_json<T: DeserializeOwned>(value: &JsonValue) -> Result<T> {
+    Ok(T::deserialize(value)?)
+}
+
+fn from_optional_json<T: DeserializeOwned>(value: Option<&JsonValue>) -> Result<Vec<T>> {
+    match value {
+        Some(JsonValue::Null) | None => Ok(Vec::new()),
+        Some(value) => from_json(value),
+    }
+}
+
+macro_rules! typed_content {
+    ( $( $i:ident ),* ) => {
+        $(
+            impl $i {
+                /// The creative_content of this Creative, typed by its creative_type
+                pub fn content(&self) -> Result<Option<CreativeContent>> {
+                    self.creative_content
+                        .as_ref()
+                        .map(|content| CreativeContent::from_json(self.creative_type, content))
+                        .transpose()
+                }
+
+                /// Set the creative_content of this Creative, along with the matching
+                /// creative_type
+                pub fn set_content(&mut self, content: CreativeContent) {
+                    self.creative_type = content.creative_type();
+                    self.creative_content = Some(content.into());
+                }
+
+                /// The VAST tracking event pixels of this Creative
+                pub fn tracking_events(&self) -> Result<Vec<TrackingEvent>> {
+                    from_optional_json(self.events.as_ref())
+                }
+
+                /// Replace the VAST tracking event pixels of this Creative
+                pub fn set_tracking_events(&mut self, events: Vec<TrackingEvent>) {
+                    self.events = Some(to_json(&events));
+                }
+
+                /// The VAST progress pixels of this Creative
+                pub fn progress_tracking_events(&self) -> Result<Vec<ProgressEvent>> {
+                    from_optional_json(self.progress_events.as_ref())
+                }
+
+                /// Replace the VAST progress pixels of this Creative
+                pub fn set_progress_tracking_events(
+                    &mut self,
+                    progress_events: Vec<ProgressEvent>,
+                ) {
+                    self.progress_events = Some(to_json(&progress_events));
+                }
+            }
+        )*
+    };
+}
+
+typed_content!(Creative, CreateCreative);
@@ -98,6 +98,16 @@ impl CreativeLineItem {
     pub fn delete_builder() -> DeleteCreativeLineItemBuilder<((),)> {
         DeleteCreativeLineItem::builder()
     }
+
+    /// Unique ID of the associated Creative
+    pub fn creative_id(&self) -> u64 {
+        self.creative_id
+    }
+
+    /// Unique ID of the associated Line Item
+    pub fn line_item_id(&self) -> u64 {
+        self.line_item_id
+    }
 }
 
 impl Resource for CreativeLineItem {
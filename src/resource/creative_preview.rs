@@ -0,0 +1,256 @@
+//! A Creative's preview_token lets anyone with its preview URL see the Creative rendered, without
+//! logging in to Buzz, so previews can be shared with clients. Requesting a new Creative Preview
+//! Token replaces the Creative's preview_token, so links shared with the old token stop working.
+
+use crate::client::{async_client::impl_async_clients, sync_client::SyncClient};
+use crate::resource::{
+    any_resource,
+    creative::{Creative, ReadCreative},
+    creative_line_item::ReadCreativeLineItem,
+    AnyResource, Create, Read, Resource,
+};
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct CreativePreviewToken {
+    /// Unique ID of the Creative Preview Token
+    pub creative_preview_token_id: u64,
+
+    /// The Creative the token previews
+    pub creative_id: u64,
+
+    /// The new preview_token of the Creative. Buzz only returns the ID of a created token, so read
+    /// the Creative back for its new preview_token.
+    pub preview_token: String,
+
+    // Undocumented
+    pub account_id: Option<u64>,
+    pub create_date: Option<String>,
+}
+
+impl CreativePreviewToken {
+    /// Create a builder for CreateCreativePreviewToken
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::CreativePreviewToken;
+    ///
+    /// let create_creative_preview_token = CreativePreviewToken::create_builder()
+    ///     .creative_id(10)
+    ///     .build();
+    ///
+    /// let creative_preview_token = beeswax_client.create(&create_creative_preview_token).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_builder() -> CreateCreativePreviewTokenBuilder<((),)> {
+        CreateCreativePreviewToken::builder()
+    }
+}
+
+impl Resource for CreativePreviewToken {
+    const NAME: &'static str = "creative_preview_token";
+}
+
+/// Request a new preview_token for a Creative
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct CreateCreativePreviewToken {
+    /// The Creative to preview
+    pub creative_id: u64,
+}
+
+impl Create<CreativePreviewToken> for CreateCreativePreviewToken {
+    fn into_resource(self, creative_preview_token_id: u64) -> CreativePreviewToken {
+        CreativePreviewToken {
+            creative_preview_token_id,
+            creative_id: self.creative_id,
+            ..Default::default()
+        }
+    }
+
+    fn create_into(
+        &self,
+        creative_preview_token: &mut CreativePreviewToken,
+        store: &mut [AnyResource],
+    ) {
+        // Buzz generates the token, in memory one unique to the request will do
+        let preview_token = format!("{:x}", creative_preview_token.creative_preview_token_id);
+        if let Some(creative) = any_resource::find_mut::<Creative>(store, self.creative_id) {
+            creative.preview_token = Some(preview_token.clone());
+        }
+        creative_preview_token.preview_token = preview_token;
+    }
+}
+
+/// A shareable preview link of a Creative
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct CreativePreview {
+    /// The Creative previewed
+    pub creative_id: u64,
+    /// Name of the Creative, e.g. "Blue Banner Ad"
+    pub creative_name: String,
+    /// URL showing the Creative rendered
+    pub preview_url: String,
+}
+
+impl CreativePreview {
+    /// Preview every Creative on the Line Item, requesting a preview_token for Creatives without
+    /// one. preview_page is the page rendering the previews, see preview_url.
+    pub fn for_line_item_sync<C: SyncClient>(
+        beeswax_client: &C,
+        line_item_id: u64,
+        preview_page: &str,
+    ) -> Result<Vec<CreativePreview>> {
+        let mut creative_previews = Vec::new();
+        for creative_line_item in read_line_item(line_item_id).read_sync(beeswax_client)? {
+            let creatives =
+                read_creative(creative_line_item.creative_id()).read_sync(beeswax_client)?;
+            let mut creative = found_creative(creatives)?;
+            if creative.preview_token.is_none() {
+                creative.refresh_preview_token_sync(beeswax_client)?;
+            }
+            creative_previews.extend(creative.preview(preview_page));
+        }
+        Ok(creative_previews)
+    }
+}
+
+/// Build the URL previewing a Creative with its preview_token. preview_page is the URL of the page
+/// rendering previews for your Buzz instance, it is given the creative_id and preview_token in its
+/// query string.
+pub fn preview_url(preview_page: &str, creative_id: u64, preview_token: &str) -> String {
+    format!(
+        "{}?creative_id={}&{}",
+        preview_page,
+        creative_id,
+        serde_urlencoded::to_string([("preview_token", preview_token)])
+            .expect("a preview token always encodes")
+    )
+}
+
+impl Creative {
+    /// The URL previewing the Creative on the preview_page, if it has a preview_token, see
+    /// preview_url
+    pub fn preview_url(&self, preview_page: &str) -> Option<String> {
+        self.preview_token
+            .as_ref()
+            .map(|preview_token| preview_url(preview_page, self.creative_id, preview_token))
+    }
+
+    /// The shareable preview of the Creative on the preview_page, if it has a preview_token
+    pub fn preview(&self, preview_page: &str) -> Option<CreativePreview> {
+        self.preview_url(preview_page)
+            .map(|preview_url| CreativePreview {
+                creative_id: self.creative_id,
+                creative_name: self.creative_name.clone(),
+                preview_url,
+            })
+    }
+
+    /// Request a new preview_token for the Creative, replacing the old one. Buzz saves the token on
+    /// the Creative, so it is read back from there.
+    pub fn refresh_preview_token_sync<C: SyncClient>(
+        &mut self,
+        beeswax_client: &C,
+    ) -> Result<String> {
+        preview_token(self).create_sync(beeswax_client)?;
+        let creatives = read_creative(self.creative_id).read_sync(beeswax_client)?;
+        self.set_preview_token(found_creative(creatives)?)
+    }
+
+    /// Take the preview_token of the Creative read back after requesting a new one
+    fn set_preview_token(&mut self, creative: Creative) -> Result<String> {
+        let preview_token = creative
+            .preview_token
+            .ok_or("creative has no preview token")?;
+        self.preview_token = Some(preview_token.clone());
+        Ok(preview_token)
+    }
+}
+
+fn preview_token(creative: &Creative) -> CreateCreativePreviewToken {
+    CreateCreativePreviewToken {
+        creative_id: creative.creative_id,
+    }
+}
+
+fn read_line_item(line_item_id: u64) -> ReadCreativeLineItem {
+    ReadCreativeLineItem::builder()
+        .line_item_id(line_item_id)
+        .build()
+}
+
+fn read_creative(creative_id: u64) -> ReadCreative {
+    ReadCreative::builder().creative_id(creative_id).build()
+}
+
+fn found_creative(mut creatives: Vec<Creative>) -> Result<Creative> {
+    creatives.pop().ok_or_else(|| "creative not found".into())
+}
+
+impl_async_clients! {
+    /// Request a new preview_token for the Creative, replacing the old one. Buzz saves the token on
+    /// the Creative, so it is read back from there.
+    pub async fn refresh_preview_token(&self, creative: &mut Creative) -> Result<String> {
+        self.create(&preview_token(creative)).await?;
+        let creatives = self.read(&read_creative(creative.creative_id)).await?;
+        creative.set_preview_token(found_creative(creatives)?)
+    }
+
+    /// Preview every Creative on the Line Item, requesting a preview_token for Creatives without
+    /// one. preview_page is the page rendering the previews, see preview_url.
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::{Creative, CreativeLineItem};
+    ///
+    /// let creative = Creative {
+    ///     creative_id: 10,
+    ///     creative_name: "Blue Banner Ad".to_string(),
+    ///     preview_token: Some("abc123".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// beeswax_client.insert(creative);
+    /// let create_creative_line_item = CreativeLineItem::create_builder()
+    ///     .creative_id(10)
+    ///     .line_item_id(20)
+    ///     .build();
+    /// beeswax_client.create(&create_creative_line_item).await?;
+    ///
+    /// let creative_previews = beeswax_client
+    ///     .line_item_previews(20, "https://buzz.example.com/creative_preview")
+    ///     .await?;
+    /// assert_eq!(
+    ///     creative_previews[0].preview_url,
+    ///     "https://buzz.example.com/creative_preview?creative_id=10&preview_token=abc123"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn line_item_previews(
+        &self,
+        line_item_id: u64,
+        preview_page: &str,
+    ) -> Result<Vec<CreativePreview>> {
+        let mut creative_previews = Vec::new();
+        for creative_line_item in self.read(&read_line_item(line_item_id)).await? {
+            let creatives = self
+                .read(&read_creative(creative_line_item.creative_id()))
+                .await?;
+            let mut creative = found_creative(creatives)?;
+            if creative.preview_token.is_none() {
+                self.refresh_preview_token(&mut creative).await?;
+            }
+            creative_previews.extend(creative.preview(preview_page));
+        }
+        Ok(creative_previews)
+    }
+}
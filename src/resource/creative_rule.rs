@@ -0,0 +1,87 @@
+//! A Creative Rule is applied to the creative_content_tag of a tag based Creative when it is saved,
+//! inserting the click and timestamp macros appropriate for the ad server that produced the tag.
+//! Creative Rules are managed by Beeswax so only GET requests are supported.
+
+use crate::resource::{creative::Creative, Read, Resource};
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct CreativeRule {
+    /// Unique ID of the Creative Rule
+    pub creative_rule_id: u64,
+
+    /// The key used to reference this rule in a Creative's creative_rule_key, e.g. "auto_detect"
+    pub creative_rule_key: String,
+
+    /// Name of the Creative Rule, e.g. "DoubleClick"
+    pub creative_rule_name: Option<String>,
+
+    /// Description of the macros inserted by the rule
+    pub creative_rule_description: Option<String>,
+
+    /// Is the Creative Rule active?
+    pub active: Option<bool>,
+}
+
+impl CreativeRule {
+    /// Create a builder for ReadCreativeRule
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::CreativeRule;
+    ///
+    /// let read_creative_rule = CreativeRule::read_builder()
+    ///     .creative_rule_key("auto_detect".to_string())
+    ///     .build();
+    ///
+    /// let creative_rules = beeswax_client.read(&read_creative_rule).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadCreativeRuleBuilder<((), (), ())> {
+        ReadCreativeRule::builder()
+    }
+}
+
+impl Resource for CreativeRule {
+    const NAME: &'static str = "creative_rule";
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadCreativeRule {
+    /// Unique ID of the Creative Rule
+    #[builder(default, setter(into))]
+    pub creative_rule_id: Option<u64>,
+    /// The key used to reference the rule, e.g. "auto_detect"
+    #[builder(default, setter(into))]
+    pub creative_rule_key: Option<String>,
+    /// Is the Creative Rule active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Read<CreativeRule> for ReadCreativeRule {}
+
+impl PartialEq<CreativeRule> for ReadCreativeRule {
+    fn eq(&self, other: &CreativeRule) -> bool {
+        (self.creative_rule_id.is_none() || self.creative_rule_id == Some(other.creative_rule_id))
+            && (self.creative_rule_key.is_none()
+                || self.creative_rule_key.as_ref() == Some(&other.creative_rule_key))
+            && (self.active.is_none() || self.active == other.active)
+    }
+}
+
+/// Create a search criteria for the rule applied to the given creative
+impl From<&Creative> for ReadCreativeRule {
+    fn from(creative: &Creative) -> Self {
+        ReadCreativeRule {
+            creative_rule_id: creative.creative_rule_id,
+            creative_rule_key: creative.creative_rule_key.clone(),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,420 @@
+//! A Creative Template defines the shape of a Creative. Every Creative references a template and
+//! its creative_content must provide the fields the template declares, for example the image, title
+//! and call to action of a native ad, or the tag of a banner. Templates are either global or belong
+//! to an Account.
+
+use crate::resource::{
+    common::CreativeType,
+    creative::{CreateCreative, Creative},
+    Create, Delete, Read, Resource,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::{collections::HashMap, error::Error, fmt};
+use typed_builder::TypedBuilder;
+
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum TemplateFieldType {
+    /// Free text
+    String,
+    /// Whole number
+    Integer,
+    /// Any number
+    Number,
+    /// true or false
+    Boolean,
+    /// A valid http or https URL
+    Url,
+    /// A snippet of HTML or JavaScript, such as a third party tag
+    Html,
+    /// The ID of a Creative Asset
+    Asset,
+    /// Any type not yet known to this crate, no type checking is done for these fields
+    #[serde(other)]
+    Other,
+}
+
+impl TemplateFieldType {
+    fn accepts(self, value: &JsonValue) -> bool {
+        match self {
+            TemplateFieldType::String | TemplateFieldType::Html => value.is_string(),
+            TemplateFieldType::Integer | TemplateFieldType::Asset => {
+                value.is_u64() || value.is_i64()
+            }
+            TemplateFieldType::Number => value.is_number(),
+            TemplateFieldType::Boolean => value.is_boolean(),
+            TemplateFieldType::Url => value
+                .as_str()
+                .map(|url| url.starts_with("http://") || url.starts_with("https://"))
+                .unwrap_or(false),
+            TemplateFieldType::Other => true,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct CreativeTemplateField {
+    /// The key the field must use within creative_content, e.g. "TITLE". Keys are upper case, as
+    /// in Buzz's global templates and the typed content in creative_content, and are matched
+    /// case insensitively.
+    pub field_name: String,
+    /// The type of value the field holds
+    pub field_type: TemplateFieldType,
+    /// Must the field be present in creative_content
+    #[serde(default)]
+    pub required: bool,
+    /// Value used by the template when the field is missing
+    pub default_value: Option<JsonValue>,
+    /// Maximum length of string fields, if limited
+    pub max_length: Option<u64>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct CreativeTemplate {
+    /// Unique ID of the Creative Template
+    pub creative_template_id: u64,
+
+    /// Name of the Creative Template, e.g. "Native app install"
+    pub creative_template_name: String,
+
+    /// The type of creative the template is for. 0=banner, 1=video, 2=native
+    pub creative_type: CreativeType,
+
+    /// The fields a Creative using this template must provide in its creative_content
+    pub fields: Option<Vec<CreativeTemplateField>>,
+
+    /// The HTML rendered for the Creative, with fields inserted as macros
+    pub template_html: Option<String>,
+
+    /// Is the template available to every Account on the Buzz instance
+    pub global: Option<bool>,
+
+    /// Notes about the Creative Template, up to 255 chars
+    pub notes: Option<String>,
+
+    /// Is the Creative Template active?
+    pub active: Option<bool>,
+
+    // Undocumented
+    pub account_id: Option<u64>,
+    pub create_date: Option<String>,
+    pub update_date: Option<String>,
+}
+
+impl CreativeTemplate {
+    /// Create a builder for CreateCreativeTemplate
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::CreativeTemplate;
+    ///
+    /// let create_creative_template = CreativeTemplate::create_builder()
+    ///     .creative_template_name("Some name")
+    ///     .build();
+    ///
+    /// let creative_template = beeswax_client.create(&create_creative_template).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_builder() -> CreateCreativeTemplateBuilder<((), (), (), (), (), ())> {
+        CreateCreativeTemplate::builder()
+    }
+
+    /// Create a builder for ReadCreativeTemplate
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::CreativeTemplate;
+    ///
+    /// let read_creative_template = CreativeTemplate::read_builder()
+    ///     .creative_template_id(1)
+    ///     .build();
+    ///
+    /// let creative_templates = beeswax_client.read(&read_creative_template).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadCreativeTemplateBuilder<((), (), (), ())> {
+        ReadCreativeTemplate::builder()
+    }
+
+    /// Create a builder for DeleteCreativeTemplate
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::CreativeTemplate;
+    ///
+    /// let delete_creative_template = CreativeTemplate::delete_builder()
+    ///     .creative_template_id(10)
+    ///     .build();
+    ///
+    /// beeswax_client.delete(&delete_creative_template).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_builder() -> DeleteCreativeTemplateBuilder<((),)> {
+        DeleteCreativeTemplate::builder()
+    }
+
+    /// Check the given creative_content provides every field declared by this template, with
+    /// values of the right type, and no fields the template does not know about.
+    /// ```
+    /// use beeswax::resource::{
+    ///     creative_template::{CreativeTemplateField, TemplateFieldType},
+    ///     CreativeTemplate,
+    /// };
+    /// use serde_json::json;
+    ///
+    /// let template = CreativeTemplate {
+    ///     fields: Some(vec![CreativeTemplateField {
+    ///         field_name: "TITLE".to_string(),
+    ///         field_type: TemplateFieldType::String,
+    ///         required: true,
+    ///         default_value: None,
+    ///         max_length: Some(25),
+    ///     }]),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert!(template.validate_content(&json!({ "TITLE": "Winter sale" })).is_ok());
+    /// assert!(template.validate_content(&json!({ "HEADLINE": "Winter sale" })).is_err());
+    /// ```
+    pub fn validate_content(
+        &self,
+        creative_content: &JsonValue,
+    ) -> Result<(), CreativeContentError> {
+        let mut problems = Vec::new();
+        let fields = self.fields.as_deref().unwrap_or(&[]);
+
+        let object = match creative_content.as_object() {
+            Some(object) => object,
+            None => {
+                problems.push("creative_content must be a JSON object".to_string());
+                return Err(CreativeContentError { problems });
+            }
+        };
+        let content: HashMap<String, &JsonValue> = object
+            .iter()
+            .map(|(key, value)| (content_key(key), value))
+            .collect();
+
+        for field in fields {
+            match content.get(&content_key(&field.field_name)).copied() {
+                None | Some(JsonValue::Null) => {
+                    if field.required && field.default_value.is_none() {
+                        problems.push(format!("missing required field {}", field.field_name));
+                    }
+                }
+                Some(value) => {
+                    if !field.field_type.accepts(value) {
+                        problems.push(format!(
+                            "field {} should be of type {:?}",
+                            field.field_name, field.field_type
+                        ));
+                    }
+                    let too_long = match (field.max_length, value.as_str()) {
+                        (Some(max_length), Some(value)) => {
+                            value.chars().count() as u64 > max_length
+                        }
+                        _ => false,
+                    };
+                    if too_long {
+                        problems.push(format!(
+                            "field {} is longer than {} characters",
+                            field.field_name,
+                            field.max_length.unwrap_or_default()
+                        ));
+                    }
+                }
+            }
+        }
+
+        for key in object.keys() {
+            if !fields
+                .iter()
+                .any(|field| content_key(&field.field_name) == content_key(key))
+            {
+                problems.push(format!("unknown field {}", key));
+            }
+        }
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(CreativeContentError { problems })
+        }
+    }
+}
+
+/// creative_content keys are compared in upper case
+fn content_key(key: &str) -> String {
+    key.to_uppercase()
+}
+
+impl Resource for CreativeTemplate {
+    const NAME: &'static str = "creative_template";
+}
+
+/// The problems found when validating creative_content against a Creative Template
+#[derive(Clone, Debug, PartialEq)]
+pub struct CreativeContentError {
+    pub problems: Vec<String>,
+}
+
+impl fmt::Display for CreativeContentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid creative_content: {}", self.problems.join(", "))
+    }
+}
+
+impl Error for CreativeContentError {}
+
+impl Creative {
+    /// Check this creative's creative_content against the template it uses. Creatives without
+    /// creative_content, such as tag creatives using creative_content_tag, are not checked.
+    pub fn validate_content(
+        &self,
+        template: &CreativeTemplate,
+    ) -> Result<(), CreativeContentError> {
+        validate_creative(
+            self.creative_template_id,
+            self.creative_content.as_ref(),
+            template,
+        )
+    }
+}
+
+impl CreateCreative {
+    /// Check this creative's creative_content against the template it uses before sending it to
+    /// Buzz. Creatives without creative_content, such as tag creatives using creative_content_tag,
+    /// are not checked.
+    pub fn validate_content(
+        &self,
+        template: &CreativeTemplate,
+    ) -> Result<(), CreativeContentError> {
+        validate_creative(
+            self.creative_template_id,
+            self.creative_content.as_ref(),
+            template,
+        )
+    }
+}
+
+fn validate_creative(
+    creative_template_id: u64,
+    creative_content: Option<&JsonValue>,
+    template: &CreativeTemplate,
+) -> Result<(), CreativeContentError> {
+    if creative_template_id != template.creative_template_id {
+        return Err(CreativeContentError {
+            problems: vec![format!(
+                "creative uses template {} not {}",
+                creative_template_id, template.creative_template_id
+            )],
+        });
+    }
+    match creative_content {
+        Some(creative_content) => template.validate_content(creative_content),
+        None => Ok(()),
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadCreativeTemplate {
+    /// Unique ID of the Creative Template
+    #[builder(default, setter(into))]
+    pub creative_template_id: Option<u64>,
+    /// Name of the Creative Template. Supports %LIKE% syntax
+    #[builder(default, setter(into))]
+    pub creative_template_name: Option<String>,
+    /// The type of creative the template is for. 0=banner, 1=video, 2=native
+    #[builder(default, setter(into))]
+    pub creative_type: Option<CreativeType>,
+    /// Is the Creative Template active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Read<CreativeTemplate> for ReadCreativeTemplate {}
+
+impl PartialEq<CreativeTemplate> for ReadCreativeTemplate {
+    fn eq(&self, other: &CreativeTemplate) -> bool {
+        (self.creative_template_id.is_none()
+            || self.creative_template_id == Some(other.creative_template_id))
+            && (self.creative_template_name.is_none()
+                || self.creative_template_name.as_ref() == Some(&other.creative_template_name))
+            && (self.creative_type.is_none() || self.creative_type == Some(other.creative_type))
+            && (self.active.is_none() || self.active == other.active)
+    }
+}
+
+/// Create a search criteria for the template used by the given creative
+impl From<&Creative> for ReadCreativeTemplate {
+    fn from(creative: &Creative) -> Self {
+        ReadCreativeTemplate {
+            creative_template_id: Some(creative.creative_template_id),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct CreateCreativeTemplate {
+    /// Name of the Creative Template, e.g. "Native app install"
+    #[builder(setter(into))]
+    pub creative_template_name: String,
+
+    /// The type of creative the template is for. 0=banner, 1=video, 2=native
+    #[builder(default, setter(into))]
+    pub creative_type: CreativeType,
+
+    /// The fields a Creative using this template must provide in its creative_content
+    #[builder(default, setter(into))]
+    pub fields: Option<Vec<CreativeTemplateField>>,
+
+    /// The HTML rendered for the Creative, with fields inserted as macros
+    #[builder(default, setter(into))]
+    pub template_html: Option<String>,
+
+    /// Notes about the Creative Template, up to 255 chars
+    #[builder(default, setter(into))]
+    pub notes: Option<String>,
+
+    /// Is the Creative Template active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Create<CreativeTemplate> for CreateCreativeTemplate {
+    fn into_resource(self, creative_template_id: u64) -> CreativeTemplate {
+        CreativeTemplate {
+            creative_template_id,
+            creative_template_name: self.creative_template_name,
+            creative_type: self.creative_type,
+            fields: self.fields,
+            template_html: self.template_html,
+            notes: self.notes,
+            active: self.active,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TypedBuilder)]
+pub struct DeleteCreativeTemplate {
+    creative_template_id: u64,
+}
+
+impl Delete<CreativeTemplate> for DeleteCreativeTemplate {}
+
+impl Delete<CreativeTemplate> for CreativeTemplate {}
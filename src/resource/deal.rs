@@ -0,0 +1,243 @@
+//! A Deal is an agreement with a publisher or exchange to buy inventory at an agreed price, such as
+//! a private marketplace (PMP) deal. The deal identifier is set by the inventory source and sent in
+//! each bid request, the inventory sources available are found in the inventory_source view. Line
+//! Items target Deals through a DealList.
+
+use crate::resource::{common::Currency, Create, Delete, Read, Resource};
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct Deal {
+    /// Unique ID of the Deal
+    pub deal_id: u64,
+
+    /// Name of the Deal, e.g. "Premium news PMP"
+    pub deal_name: String,
+
+    /// ID of the deal as set by the inventory source and sent in bid requests
+    pub deal_identifier: String,
+
+    /// Key of the inventory source offering the Deal, e.g. "google"
+    pub inventory_source: String,
+
+    /// Minimum bid price of the Deal, as a CPM
+    pub floor_price: Option<f64>,
+
+    /// Currency of the floor price
+    pub currency: Option<Currency>,
+
+    /// The Advertiser the Deal may be used by. If empty, every Advertiser may use the Deal.
+    pub advertiser_id: Option<u64>,
+
+    /// Date the Deal starts, e.g. "2020-01-01 00:00:00"
+    pub start_date: Option<String>,
+
+    /// Date the Deal ends, e.g. "2020-12-31 23:59:59"
+    pub end_date: Option<String>,
+
+    /// An alternative id to lookup the Deal, if desired
+    pub alternative_id: Option<String>,
+
+    /// Notes about the Deal, up to 255 chars
+    pub notes: Option<String>,
+
+    /// Is the Deal active?
+    pub active: Option<bool>,
+
+    // Undocumented
+    pub account_id: Option<u64>,
+    pub create_date: Option<String>,
+    pub update_date: Option<String>,
+}
+
+impl Deal {
+    /// Create a builder for CreateDeal
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::{common::Currency, Deal};
+    ///
+    /// let create_deal = Deal::create_builder()
+    ///     .deal_name("Premium news PMP")
+    ///     .deal_identifier("PMP-1234")
+    ///     .inventory_source("google")
+    ///     .floor_price(2.5)
+    ///     .currency(Currency::UnitedStatesDollar)
+    ///     .build();
+    ///
+    /// let deal = beeswax_client.create(&create_deal).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn create_builder() -> CreateDealBuilder<((), (), (), (), (), (), (), (), (), (), ())> {
+        CreateDeal::builder()
+    }
+
+    /// Create a builder for ReadDeal
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::Deal;
+    ///
+    /// let read_deal = Deal::read_builder()
+    ///     .inventory_source("google".to_string())
+    ///     .build();
+    ///
+    /// let deals = beeswax_client.read(&read_deal).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadDealBuilder<((), (), (), (), (), (), ())> {
+        ReadDeal::builder()
+    }
+
+    /// Create a builder for DeleteDeal
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::Deal;
+    ///
+    /// let delete_deal = Deal::delete_builder()
+    ///     .deal_id(10)
+    ///     .build();
+    ///
+    /// beeswax_client.delete(&delete_deal).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_builder() -> DeleteDealBuilder<((),)> {
+        DeleteDeal::builder()
+    }
+}
+
+impl Resource for Deal {
+    const NAME: &'static str = "deal";
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadDeal {
+    /// Unique ID of the Deal
+    #[builder(default, setter(into))]
+    pub deal_id: Option<u64>,
+    /// Name of the Deal. Supports %LIKE% syntax
+    #[builder(default, setter(into))]
+    pub deal_name: Option<String>,
+    /// ID of the deal as set by the inventory source
+    #[builder(default, setter(into))]
+    pub deal_identifier: Option<String>,
+    /// Key of the inventory source offering the Deal
+    #[builder(default, setter(into))]
+    pub inventory_source: Option<String>,
+    /// The Advertiser the Deal may be used by
+    #[builder(default, setter(into))]
+    pub advertiser_id: Option<u64>,
+    /// An alternative id to lookup the Deal, if desired
+    #[builder(default, setter(into))]
+    pub alternative_id: Option<String>,
+    /// Is the Deal active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Read<Deal> for ReadDeal {}
+
+impl PartialEq<Deal> for ReadDeal {
+    fn eq(&self, other: &Deal) -> bool {
+        (self.deal_id.is_none() || self.deal_id == Some(other.deal_id))
+            && (self.deal_name.is_none() || self.deal_name.as_ref() == Some(&other.deal_name))
+            && (self.deal_identifier.is_none()
+                || self.deal_identifier.as_ref() == Some(&other.deal_identifier))
+            && (self.inventory_source.is_none()
+                || self.inventory_source.as_ref() == Some(&other.inventory_source))
+            && (self.advertiser_id.is_none() || self.advertiser_id == other.advertiser_id)
+            && (self.alternative_id.is_none() || self.alternative_id == other.alternative_id)
+            && (self.active.is_none() || self.active == other.active)
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct CreateDeal {
+    /// Name of the Deal, e.g. "Premium news PMP"
+    #[builder(setter(into))]
+    pub deal_name: String,
+
+    /// ID of the deal as set by the inventory source and sent in bid requests
+    #[builder(setter(into))]
+    pub deal_identifier: String,
+
+    /// Key of the inventory source offering the Deal, e.g. "google"
+    #[builder(setter(into))]
+    pub inventory_source: String,
+
+    /// Minimum bid price of the Deal, as a CPM
+    #[builder(default, setter(into))]
+    pub floor_price: Option<f64>,
+
+    /// Currency of the floor price
+    #[builder(default, setter(into))]
+    pub currency: Option<Currency>,
+
+    /// The Advertiser the Deal may be used by. If empty, every Advertiser may use the Deal.
+    #[builder(default, setter(into))]
+    pub advertiser_id: Option<u64>,
+
+    /// Date the Deal starts, e.g. "2020-01-01 00:00:00"
+    #[builder(default, setter(into))]
+    pub start_date: Option<String>,
+
+    /// Date the Deal ends, e.g. "2020-12-31 23:59:59"
+    #[builder(default, setter(into))]
+    pub end_date: Option<String>,
+
+    /// An alternative id to lookup the Deal, if desired
+    #[builder(default, setter(into))]
+    pub alternative_id: Option<String>,
+
+    /// Notes about the Deal, up to 255 chars
+    #[builder(default, setter(into))]
+    pub notes: Option<String>,
+
+    /// Is the Deal active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Create<Deal> for CreateDeal {
+    fn into_resource(self, deal_id: u64) -> Deal {
+        Deal {
+            deal_id,
+            deal_name: self.deal_name,
+            deal_identifier: self.deal_identifier,
+            inventory_source: self.inventory_source,
+            floor_price: self.floor_price,
+            currency: self.currency,
+            advertiser_id: self.advertiser_id,
+            start_date: self.start_date,
+            end_date: self.end_date,
+            alternative_id: self.alternative_id,
+            notes: self.notes,
+            active: self.active,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TypedBuilder)]
+pub struct DeleteDeal {
+    deal_id: u64,
+}
+
+impl Delete<Deal> for DeleteDeal {}
+
+impl Delete<Deal> for Deal {}
@@ -0,0 +1,207 @@
+//! A Deal List groups Deals so they can be targeted together by Line Items, for example every PMP
+//! deal with news publishers. Updating the Deal List changes the Deals targeted by every Line Item
+//! using it.
+
+use crate::resource::{deal::Deal, Create, Delete, Read, Resource};
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct DealList {
+    /// Unique ID of the Deal List
+    pub deal_list_id: u64,
+
+    /// Name of the Deal List, e.g. "News PMPs"
+    pub deal_list_name: String,
+
+    /// IDs of the Deals in the list
+    pub deal_ids: Vec<u64>,
+
+    /// The Advertiser the Deal List may be used by. If empty, every Advertiser may use the list.
+    pub advertiser_id: Option<u64>,
+
+    /// An alternative id to lookup the Deal List, if desired
+    pub alternative_id: Option<String>,
+
+    /// Notes about the Deal List, up to 255 chars
+    pub notes: Option<String>,
+
+    /// Is the Deal List active?
+    pub active: Option<bool>,
+
+    // Undocumented
+    pub account_id: Option<u64>,
+    pub create_date: Option<String>,
+    pub update_date: Option<String>,
+}
+
+impl DealList {
+    /// Create a builder for CreateDealList
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::DealList;
+    ///
+    /// let create_deal_list = DealList::create_builder()
+    ///     .deal_list_name("News PMPs")
+    ///     .deal_ids(vec![1, 2])
+    ///     .build();
+    ///
+    /// let deal_list = beeswax_client.create(&create_deal_list).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_builder() -> CreateDealListBuilder<((), (), (), (), (), ())> {
+        CreateDealList::builder()
+    }
+
+    /// Create a builder for ReadDealList
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::DealList;
+    ///
+    /// let read_deal_list = DealList::read_builder()
+    ///     .deal_list_name("News PMPs".to_string())
+    ///     .build();
+    ///
+    /// let deal_lists = beeswax_client.read(&read_deal_list).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadDealListBuilder<((), (), (), (), ())> {
+        ReadDealList::builder()
+    }
+
+    /// Create a builder for DeleteDealList
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::DealList;
+    ///
+    /// let delete_deal_list = DealList::delete_builder()
+    ///     .deal_list_id(10)
+    ///     .build();
+    ///
+    /// beeswax_client.delete(&delete_deal_list).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_builder() -> DeleteDealListBuilder<((),)> {
+        DeleteDealList::builder()
+    }
+
+    /// Is the Deal in the list?
+    pub fn contains(&self, deal: &Deal) -> bool {
+        self.deal_ids.contains(&deal.deal_id)
+    }
+
+    /// Add the Deal to the list, if it is not already in it. Update the Deal List to save the
+    /// change.
+    pub fn add_deal(&mut self, deal: &Deal) {
+        if !self.contains(deal) {
+            self.deal_ids.push(deal.deal_id);
+        }
+    }
+
+    /// Remove the Deal from the list. Update the Deal List to save the change.
+    pub fn remove_deal(&mut self, deal: &Deal) {
+        self.deal_ids.retain(|deal_id| *deal_id != deal.deal_id);
+    }
+}
+
+impl Resource for DealList {
+    const NAME: &'static str = "deal_list";
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadDealList {
+    /// Unique ID of the Deal List
+    #[builder(default, setter(into))]
+    pub deal_list_id: Option<u64>,
+    /// Name of the Deal List. Supports %LIKE% syntax
+    #[builder(default, setter(into))]
+    pub deal_list_name: Option<String>,
+    /// The Advertiser the Deal List may be used by
+    #[builder(default, setter(into))]
+    pub advertiser_id: Option<u64>,
+    /// An alternative id to lookup the Deal List, if desired
+    #[builder(default, setter(into))]
+    pub alternative_id: Option<String>,
+    /// Is the Deal List active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Read<DealList> for ReadDealList {}
+
+impl PartialEq<DealList> for ReadDealList {
+    fn eq(&self, other: &DealList) -> bool {
+        (self.deal_list_id.is_none() || self.deal_list_id == Some(other.deal_list_id))
+            && (self.deal_list_name.is_none()
+                || self.deal_list_name.as_ref() == Some(&other.deal_list_name))
+            && (self.advertiser_id.is_none() || self.advertiser_id == other.advertiser_id)
+            && (self.alternative_id.is_none() || self.alternative_id == other.alternative_id)
+            && (self.active.is_none() || self.active == other.active)
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct CreateDealList {
+    /// Name of the Deal List, e.g. "News PMPs"
+    #[builder(setter(into))]
+    pub deal_list_name: String,
+
+    /// IDs of the Deals in the list
+    #[builder(default)]
+    pub deal_ids: Vec<u64>,
+
+    /// The Advertiser the Deal List may be used by. If empty, every Advertiser may use the list.
+    #[builder(default, setter(into))]
+    pub advertiser_id: Option<u64>,
+
+    /// An alternative id to lookup the Deal List, if desired
+    #[builder(default, setter(into))]
+    pub alternative_id: Option<String>,
+
+    /// Notes about the Deal List, up to 255 chars
+    #[builder(default, setter(into))]
+    pub notes: Option<String>,
+
+    /// Is the Deal List active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Create<DealList> for CreateDealList {
+    fn into_resource(self, deal_list_id: u64) -> DealList {
+        DealList {
+            deal_list_id,
+            deal_list_name: self.deal_list_name,
+            deal_ids: self.deal_ids,
+            advertiser_id: self.advertiser_id,
+            alternative_id: self.alternative_id,
+            notes: self.notes,
+            active: self.active,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TypedBuilder)]
+pub struct DeleteDealList {
+    deal_list_id: u64,
+}
+
+impl Delete<DealList> for DeleteDealList {}
+
+impl Delete<DealList> for DealList {}
@@ -0,0 +1,191 @@
+//! A Delivery Modifier shapes how a Line Item's budget is delivered across attributes of the
+//! auction, for example spending twice as much on mobile as desktop. Delivery Modifiers can be set
+//! on a Line Item directly, or on a Campaign to apply to all of its Line Items.
+
+use crate::resource::{
+    common::TermValue, modifier_term::modifier_term, Create, Delete, Read, Resource,
+};
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+modifier_term!(
+    /// A term of a Delivery Modifier, e.g.
+    /// `DeliveryModifierTerm::new("platform.device_type", 1, 2.0)`
+    DeliveryModifierTerm {
+        /// Relative share of delivery for auctions matching the term, compared to the other terms
+        weight
+    }
+);
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct DeliveryModifier {
+    /// Unique ID of the Delivery Modifier
+    pub delivery_modifier_id: u64,
+
+    /// Name of the Delivery Modifier, e.g. "Mobile heavy"
+    pub delivery_modifier_name: String,
+
+    /// The terms to match against the auction. Delivery is split between the terms in proportion
+    /// to their weights.
+    pub terms: Vec<DeliveryModifierTerm>,
+
+    /// An alternative id to lookup the Delivery Modifier, if desired
+    pub alternative_id: Option<String>,
+
+    /// Notes about the Delivery Modifier, up to 255 chars
+    pub notes: Option<String>,
+
+    /// Is the Delivery Modifier active?
+    pub active: Option<bool>,
+
+    // Undocumented
+    pub account_id: Option<u64>,
+    pub create_date: Option<String>,
+    pub update_date: Option<String>,
+}
+
+impl DeliveryModifier {
+    /// Create a builder for CreateDeliveryModifier
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::{delivery_modifier::DeliveryModifierTerm, DeliveryModifier};
+    ///
+    /// let create_delivery_modifier = DeliveryModifier::create_builder()
+    ///     .delivery_modifier_name("Some name")
+    ///     .terms(vec![DeliveryModifierTerm::new("platform.device_type", 1, 2.0)])
+    ///     .build();
+    ///
+    /// let delivery_modifier = beeswax_client.create(&create_delivery_modifier).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_builder() -> CreateDeliveryModifierBuilder<((), (), (), (), ())> {
+        CreateDeliveryModifier::builder()
+    }
+
+    /// Create a builder for ReadDeliveryModifier
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::DeliveryModifier;
+    ///
+    /// let read_delivery_modifier = DeliveryModifier::read_builder()
+    ///     .delivery_modifier_name("Some name".to_string())
+    ///     .build();
+    ///
+    /// let delivery_modifiers = beeswax_client.read(&read_delivery_modifier).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadDeliveryModifierBuilder<((), (), (), ())> {
+        ReadDeliveryModifier::builder()
+    }
+
+    /// Create a builder for DeleteDeliveryModifier
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::DeliveryModifier;
+    ///
+    /// let delete_delivery_modifier = DeliveryModifier::delete_builder()
+    ///     .delivery_modifier_id(10)
+    ///     .build();
+    ///
+    /// beeswax_client.delete(&delete_delivery_modifier).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_builder() -> DeleteDeliveryModifierBuilder<((),)> {
+        DeleteDeliveryModifier::builder()
+    }
+}
+
+impl Resource for DeliveryModifier {
+    const NAME: &'static str = "delivery_modifier";
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadDeliveryModifier {
+    /// Unique ID of the Delivery Modifier
+    #[builder(default, setter(into))]
+    pub delivery_modifier_id: Option<u64>,
+    /// Name of the Delivery Modifier. Supports %LIKE% syntax
+    #[builder(default, setter(into))]
+    pub delivery_modifier_name: Option<String>,
+    /// An alternative id to lookup the Delivery Modifier, if desired
+    #[builder(default, setter(into))]
+    pub alternative_id: Option<String>,
+    /// Is the Delivery Modifier active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Read<DeliveryModifier> for ReadDeliveryModifier {}
+
+impl PartialEq<DeliveryModifier> for ReadDeliveryModifier {
+    fn eq(&self, other: &DeliveryModifier) -> bool {
+        (self.delivery_modifier_id.is_none()
+            || self.delivery_modifier_id == Some(other.delivery_modifier_id))
+            && (self.delivery_modifier_name.is_none()
+                || self.delivery_modifier_name.as_ref() == Some(&other.delivery_modifier_name))
+            && (self.alternative_id.is_none() || self.alternative_id == other.alternative_id)
+            && (self.active.is_none() || self.active == other.active)
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct CreateDeliveryModifier {
+    /// Name of the Delivery Modifier, e.g. "Mobile heavy"
+    #[builder(setter(into))]
+    pub delivery_modifier_name: String,
+
+    /// The terms to match against the auction. Delivery is split between the terms in proportion
+    /// to their weights.
+    #[builder(default)]
+    pub terms: Vec<DeliveryModifierTerm>,
+
+    /// An alternative id to lookup the Delivery Modifier, if desired
+    #[builder(default, setter(into))]
+    pub alternative_id: Option<String>,
+
+    /// Notes about the Delivery Modifier, up to 255 chars
+    #[builder(default, setter(into))]
+    pub notes: Option<String>,
+
+    /// Is the Delivery Modifier active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Create<DeliveryModifier> for CreateDeliveryModifier {
+    fn into_resource(self, delivery_modifier_id: u64) -> DeliveryModifier {
+        DeliveryModifier {
+            delivery_modifier_id,
+            delivery_modifier_name: self.delivery_modifier_name,
+            terms: self.terms,
+            alternative_id: self.alternative_id,
+            notes: self.notes,
+            active: self.active,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TypedBuilder)]
+pub struct DeleteDeliveryModifier {
+    delivery_modifier_id: u64,
+}
+
+impl Delete<DeliveryModifier> for DeleteDeliveryModifier {}
+
+impl Delete<DeliveryModifier> for DeliveryModifier {}
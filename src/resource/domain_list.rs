@@ -0,0 +1,220 @@
+//! A Domain List is a set of domains, such as "example.com", for Targeting Templates to allow or
+//! block. Lists can be edited in bulk and saved with update, or replaced by uploading a file with
+//! one domain per line. The most commonly seen domains are found in the lookup_domains view.
+
+use crate::resource::{
+    list_item::{self, list_items, normalize_domain},
+    Create, Delete, Read, Resource,
+};
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct DomainList {
+    /// Unique ID of the Domain List
+    pub domain_list_id: u64,
+
+    /// Name of the Domain List, e.g. "Brand safety blocks"
+    pub domain_list_name: String,
+
+    /// The domains in the list, e.g. "example.com". Domains are kept lower case, without a scheme
+    /// or trailing slash.
+    pub domains: Vec<String>,
+
+    /// The Advertiser the Domain List may be used by. If empty, every Advertiser may use the list.
+    pub advertiser_id: Option<u64>,
+
+    /// An alternative id to lookup the Domain List, if desired
+    pub alternative_id: Option<String>,
+
+    /// Notes about the Domain List, up to 255 chars
+    pub notes: Option<String>,
+
+    /// Is the Domain List active?
+    pub active: Option<bool>,
+
+    // Undocumented
+    pub account_id: Option<u64>,
+    pub create_date: Option<String>,
+    pub update_date: Option<String>,
+}
+
+impl DomainList {
+    /// Create a builder for CreateDomainList
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::DomainList;
+    ///
+    /// let create_domain_list = DomainList::create_builder()
+    ///     .domain_list_name("Brand safety blocks")
+    ///     .domains(vec!["example.com".to_string()])
+    ///     .build();
+    ///
+    /// let domain_list = beeswax_client.create(&create_domain_list).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_builder() -> CreateDomainListBuilder<((), (), (), (), (), ())> {
+        CreateDomainList::builder()
+    }
+
+    /// Create a builder for ReadDomainList
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::DomainList;
+    ///
+    /// let read_domain_list = DomainList::read_builder()
+    ///     .domain_list_name("Brand safety blocks".to_string())
+    ///     .build();
+    ///
+    /// let domain_lists = beeswax_client.read(&read_domain_list).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadDomainListBuilder<((), (), (), (), ())> {
+        ReadDomainList::builder()
+    }
+
+    /// Create a builder for DeleteDomainList
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::DomainList;
+    ///
+    /// let delete_domain_list = DomainList::delete_builder()
+    ///     .domain_list_id(10)
+    ///     .build();
+    ///
+    /// beeswax_client.delete(&delete_domain_list).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_builder() -> DeleteDomainListBuilder<((),)> {
+        DeleteDomainList::builder()
+    }
+}
+
+impl Resource for DomainList {
+    const NAME: &'static str = "domain_list";
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadDomainList {
+    /// Unique ID of the Domain List
+    #[builder(default, setter(into))]
+    pub domain_list_id: Option<u64>,
+    /// Name of the Domain List. Supports %LIKE% syntax
+    #[builder(default, setter(into))]
+    pub domain_list_name: Option<String>,
+    /// The Advertiser the Domain List may be used by
+    #[builder(default, setter(into))]
+    pub advertiser_id: Option<u64>,
+    /// An alternative id to lookup the Domain List, if desired
+    #[builder(default, setter(into))]
+    pub alternative_id: Option<String>,
+    /// Is the Domain List active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Read<DomainList> for ReadDomainList {}
+
+impl PartialEq<DomainList> for ReadDomainList {
+    fn eq(&self, other: &DomainList) -> bool {
+        (self.domain_list_id.is_none() || self.domain_list_id == Some(other.domain_list_id))
+            && (self.domain_list_name.is_none()
+                || self.domain_list_name.as_ref() == Some(&other.domain_list_name))
+            && (self.advertiser_id.is_none() || self.advertiser_id == other.advertiser_id)
+            && (self.alternative_id.is_none() || self.alternative_id == other.alternative_id)
+            && (self.active.is_none() || self.active == other.active)
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct CreateDomainList {
+    /// Name of the Domain List, e.g. "Brand safety blocks"
+    #[builder(setter(into))]
+    pub domain_list_name: String,
+
+    /// The domains in the list, e.g. "example.com"
+    #[builder(default)]
+    #[serde(serialize_with = "list_item::serialize_domains")]
+    pub domains: Vec<String>,
+
+    /// The Advertiser the Domain List may be used by. If empty, every Advertiser may use the list.
+    #[builder(default, setter(into))]
+    pub advertiser_id: Option<u64>,
+
+    /// An alternative id to lookup the Domain List, if desired
+    #[builder(default, setter(into))]
+    pub alternative_id: Option<String>,
+
+    /// Notes about the Domain List, up to 255 chars
+    #[builder(default, setter(into))]
+    pub notes: Option<String>,
+
+    /// Is the Domain List active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Create<DomainList> for CreateDomainList {
+    fn into_resource(self, domain_list_id: u64) -> DomainList {
+        DomainList {
+            domain_list_id,
+            domain_list_name: self.domain_list_name,
+            domains: list_item::normalized_items(self.domains, normalize_domain),
+            advertiser_id: self.advertiser_id,
+            alternative_id: self.alternative_id,
+            notes: self.notes,
+            active: self.active,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TypedBuilder)]
+pub struct DeleteDomainList {
+    domain_list_id: u64,
+}
+
+impl Delete<DomainList> for DeleteDomainList {}
+
+impl Delete<DomainList> for DomainList {}
+
+/// Replace the domains of a Domain List with those in a file, one domain per line
+#[derive(Clone, Debug, TypedBuilder)]
+pub struct UploadDomainList {
+    /// Unique ID of the Domain List the file replaces the domains of
+    pub domain_list_id: u64,
+    /// Name of the file, e.g. "blocks.txt"
+    #[builder(setter(into))]
+    pub file_name: String,
+    /// Raw content of the file
+    pub content: Vec<u8>,
+}
+
+list_items!(
+    DomainList {
+        domains,
+        normalize_domain,
+        add_domains,
+        remove_domains
+    },
+    UploadDomainList {
+        domain_list_id,
+        from_domains,
+        domains
+    }
+);
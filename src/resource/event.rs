@@ -0,0 +1,266 @@
+//! An Event is an action taken by a user that an Advertiser wants to track, such as a purchase or
+//! sign up, typically recorded with a pixel placed on the Advertiser's site. Conversions are
+//! attributed to impressions and clicks using the Advertiser's conversion_method_id, within the
+//! attribution windows set on the Event. The tags to place on a site are fetched with EventTag.
+
+use crate::resource::{common::ConversionMethod, Advertiser, Create, Delete, Read, Resource};
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct Event {
+    /// Unique ID of the Event
+    pub event_id: u64,
+
+    /// Name of the Event, e.g. "Purchase"
+    pub event_name: String,
+
+    /// The Advertiser the Event belongs to
+    pub advertiser_id: u64,
+
+    /// Type of the Event as found in the event_types view
+    pub event_type_id: Option<u64>,
+
+    /// Default value of a conversion, used when the pixel doesn't pass a value
+    pub value: Option<f64>,
+
+    /// Minutes after a click in which a conversion is credited to it
+    pub click_window: Option<u64>,
+
+    /// Minutes after an impression in which a conversion is credited to it. Only used when the
+    /// Advertiser's conversion method counts views.
+    pub view_window: Option<u64>,
+
+    /// An alternative id to lookup the Event, if desired
+    pub alternative_id: Option<String>,
+
+    /// Notes about the Event, up to 255 chars
+    pub notes: Option<String>,
+
+    /// Is the Event active?
+    pub active: Option<bool>,
+
+    // Undocumented
+    pub account_id: Option<u64>,
+    pub create_date: Option<String>,
+    pub update_date: Option<String>,
+}
+
+impl Event {
+    /// Create a builder for CreateEvent
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::Event;
+    ///
+    /// let create_event = Event::create_builder()
+    ///     .event_name("Purchase")
+    ///     .advertiser_id(1)
+    ///     .click_window(30 * 24 * 60)
+    ///     .build();
+    ///
+    /// let event = beeswax_client.create(&create_event).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn create_builder() -> CreateEventBuilder<((), (), (), (), (), (), (), (), ())> {
+        CreateEvent::builder()
+    }
+
+    /// Create a builder for ReadEvent
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::Event;
+    ///
+    /// let read_event = Event::read_builder()
+    ///     .advertiser_id(1)
+    ///     .build();
+    ///
+    /// let events = beeswax_client.read(&read_event).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadEventBuilder<((), (), (), (), ())> {
+        ReadEvent::builder()
+    }
+
+    /// Create a builder for DeleteEvent
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::Event;
+    ///
+    /// let delete_event = Event::delete_builder()
+    ///     .event_id(10)
+    ///     .build();
+    ///
+    /// beeswax_client.delete(&delete_event).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_builder() -> DeleteEventBuilder<((),)> {
+        DeleteEvent::builder()
+    }
+
+    /// Combine the Advertiser's conversion method with this Event's windows. Returns an error if
+    /// the Event belongs to another Advertiser, or sets a window the conversion method doesn't use.
+    pub fn attribution(&self, advertiser: &Advertiser) -> Result<ConversionAttribution> {
+        if self.advertiser_id != advertiser.advertiser_id {
+            return Err(format!(
+                "event {} belongs to advertiser {} not {}",
+                self.event_id, self.advertiser_id, advertiser.advertiser_id
+            )
+            .into());
+        }
+        ConversionAttribution::new(
+            advertiser.conversion_method_id.clone(),
+            self.click_window,
+            self.view_window,
+        )
+    }
+}
+
+impl Resource for Event {
+    const NAME: &'static str = "event";
+}
+
+/// How conversions of an Event are credited to impressions and clicks
+#[derive(Clone, Debug, PartialEq)]
+pub struct ConversionAttribution {
+    /// The Advertiser's conversion method
+    pub conversion_method: ConversionMethod,
+    /// Minutes after a click in which a conversion is credited to it, if clicks are counted
+    pub click_window: Option<u64>,
+    /// Minutes after an impression in which a conversion is credited to it, if views are counted
+    pub view_window: Option<u64>,
+}
+
+impl ConversionAttribution {
+    /// Check the windows are used by the conversion method
+    pub fn new(
+        conversion_method: ConversionMethod,
+        click_window: Option<u64>,
+        view_window: Option<u64>,
+    ) -> Result<ConversionAttribution> {
+        if click_window.is_some() && !conversion_method.counts_clicks() {
+            return Err(format!("{:?} does not count clicks", conversion_method).into());
+        }
+        if view_window.is_some() && !conversion_method.counts_views() {
+            return Err(format!("{:?} does not count views", conversion_method).into());
+        }
+        Ok(ConversionAttribution {
+            conversion_method,
+            click_window,
+            view_window,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadEvent {
+    /// Unique ID of the Event
+    #[builder(default, setter(into))]
+    pub event_id: Option<u64>,
+    /// Name of the Event. Supports %LIKE% syntax
+    #[builder(default, setter(into))]
+    pub event_name: Option<String>,
+    /// The Advertiser the Event belongs to
+    #[builder(default, setter(into))]
+    pub advertiser_id: Option<u64>,
+    /// An alternative id to lookup the Event, if desired
+    #[builder(default, setter(into))]
+    pub alternative_id: Option<String>,
+    /// Is the Event active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Read<Event> for ReadEvent {}
+
+impl PartialEq<Event> for ReadEvent {
+    fn eq(&self, other: &Event) -> bool {
+        (self.event_id.is_none() || self.event_id == Some(other.event_id))
+            && (self.event_name.is_none() || self.event_name.as_ref() == Some(&other.event_name))
+            && (self.advertiser_id.is_none() || self.advertiser_id == Some(other.advertiser_id))
+            && (self.alternative_id.is_none() || self.alternative_id == other.alternative_id)
+            && (self.active.is_none() || self.active == other.active)
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct CreateEvent {
+    /// Name of the Event, e.g. "Purchase"
+    #[builder(setter(into))]
+    pub event_name: String,
+
+    /// The Advertiser the Event belongs to
+    pub advertiser_id: u64,
+
+    /// Type of the Event as found in the event_types view
+    #[builder(default, setter(into))]
+    pub event_type_id: Option<u64>,
+
+    /// Default value of a conversion, used when the pixel doesn't pass a value
+    #[builder(default, setter(into))]
+    pub value: Option<f64>,
+
+    /// Minutes after a click in which a conversion is credited to it
+    #[builder(default, setter(into))]
+    pub click_window: Option<u64>,
+
+    /// Minutes after an impression in which a conversion is credited to it. Only used when the
+    /// Advertiser's conversion method counts views.
+    #[builder(default, setter(into))]
+    pub view_window: Option<u64>,
+
+    /// An alternative id to lookup the Event, if desired
+    #[builder(default, setter(into))]
+    pub alternative_id: Option<String>,
+
+    /// Notes about the Event, up to 255 chars
+    #[builder(default, setter(into))]
+    pub notes: Option<String>,
+
+    /// Is the Event active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Create<Event> for CreateEvent {
+    fn into_resource(self, event_id: u64) -> Event {
+        Event {
+            event_id,
+            event_name: self.event_name,
+            advertiser_id: self.advertiser_id,
+            event_type_id: self.event_type_id,
+            value: self.value,
+            click_window: self.click_window,
+            view_window: self.view_window,
+            alternative_id: self.alternative_id,
+            notes: self.notes,
+            active: self.active,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TypedBuilder)]
+pub struct DeleteEvent {
+    event_id: u64,
+}
+
+impl Delete<Event> for DeleteEvent {}
+
+impl Delete<Event> for Event {}
@@ -0,0 +1,83 @@
+//! An Event Tag is the markup to place on a page to record an Event, such as an image pixel or a
+//! script tag. Tags are generated by Buzz for a given Event and tag type, so only GET requests are
+//! supported.
+
+use crate::resource::{common::EventTagType, event::Event, Read, Resource};
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct EventTag {
+    /// The Event the tag records
+    pub event_id: u64,
+
+    /// The kind of tag
+    pub tag_type: EventTagType,
+
+    /// Does the tag use https?
+    pub secure: Option<bool>,
+
+    /// The markup to place on the page
+    pub tag: String,
+}
+
+impl EventTag {
+    /// Create a builder for ReadEventTag
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::{common::EventTagType, EventTag};
+    ///
+    /// let read_event_tag = EventTag::read_builder()
+    ///     .event_id(1)
+    ///     .tag_type(EventTagType::JavaScript)
+    ///     .build();
+    ///
+    /// let event_tags = beeswax_client.read(&read_event_tag).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadEventTagBuilder<((), (), ())> {
+        ReadEventTag::builder()
+    }
+}
+
+impl Resource for EventTag {
+    const NAME: &'static str = "event_tag";
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadEventTag {
+    /// The Event the tag records
+    #[builder(default, setter(into))]
+    pub event_id: Option<u64>,
+    /// The kind of tag
+    #[builder(default, setter(into))]
+    pub tag_type: Option<EventTagType>,
+    /// Does the tag use https?
+    #[builder(default, setter(into))]
+    pub secure: Option<bool>,
+}
+
+impl Read<EventTag> for ReadEventTag {}
+
+impl PartialEq<EventTag> for ReadEventTag {
+    fn eq(&self, other: &EventTag) -> bool {
+        (self.event_id.is_none() || self.event_id == Some(other.event_id))
+            && (self.tag_type.is_none() || self.tag_type == Some(other.tag_type))
+            && (self.secure.is_none() || self.secure == other.secure)
+    }
+}
+
+/// Create a search criteria for every tag of the given Event
+impl From<&Event> for ReadEventTag {
+    fn from(event: &Event) -> Self {
+        ReadEventTag {
+            event_id: Some(event.event_id),
+            ..Default::default()
+        }
+    }
+}
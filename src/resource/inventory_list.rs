@@ -0,0 +1,221 @@
+//! An Inventory List is a set of placements for Targeting Templates to allow or block. Each
+//! placement is a site_id and placement_id joined by a slash, such as "12345/top_banner". Lists
+//! can be edited in bulk and saved with update, or replaced by uploading a file with one placement
+//! per line. The most commonly seen placements are found in the lookup_inventory view.
+
+use crate::resource::{
+    list_item::{self, list_items, normalize_item},
+    Create, Delete, Read, Resource,
+};
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct InventoryList {
+    /// Unique ID of the Inventory List
+    pub inventory_list_id: u64,
+
+    /// Name of the Inventory List, e.g. "Premium placements"
+    pub inventory_list_name: String,
+
+    /// Placements in the list as site_id/placement_id, e.g. "12345/top_banner"
+    pub placements: Vec<String>,
+
+    /// The Advertiser the Inventory List may be used by. If empty, every Advertiser may use the list.
+    pub advertiser_id: Option<u64>,
+
+    /// An alternative id to lookup the Inventory List, if desired
+    pub alternative_id: Option<String>,
+
+    /// Notes about the Inventory List, up to 255 chars
+    pub notes: Option<String>,
+
+    /// Is the Inventory List active?
+    pub active: Option<bool>,
+
+    // Undocumented
+    pub account_id: Option<u64>,
+    pub create_date: Option<String>,
+    pub update_date: Option<String>,
+}
+
+impl InventoryList {
+    /// Create a builder for CreateInventoryList
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::InventoryList;
+    ///
+    /// let create_inventory_list = InventoryList::create_builder()
+    ///     .inventory_list_name("Premium placements")
+    ///     .placements(vec!["12345/top_banner".to_string()])
+    ///     .build();
+    ///
+    /// let inventory_list = beeswax_client.create(&create_inventory_list).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_builder() -> CreateInventoryListBuilder<((), (), (), (), (), ())> {
+        CreateInventoryList::builder()
+    }
+
+    /// Create a builder for ReadInventoryList
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::InventoryList;
+    ///
+    /// let read_inventory_list = InventoryList::read_builder()
+    ///     .inventory_list_name("Premium placements".to_string())
+    ///     .build();
+    ///
+    /// let inventory_lists = beeswax_client.read(&read_inventory_list).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadInventoryListBuilder<((), (), (), (), ())> {
+        ReadInventoryList::builder()
+    }
+
+    /// Create a builder for DeleteInventoryList
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::InventoryList;
+    ///
+    /// let delete_inventory_list = InventoryList::delete_builder()
+    ///     .inventory_list_id(10)
+    ///     .build();
+    ///
+    /// beeswax_client.delete(&delete_inventory_list).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_builder() -> DeleteInventoryListBuilder<((),)> {
+        DeleteInventoryList::builder()
+    }
+}
+
+impl Resource for InventoryList {
+    const NAME: &'static str = "inventory_list";
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadInventoryList {
+    /// Unique ID of the Inventory List
+    #[builder(default, setter(into))]
+    pub inventory_list_id: Option<u64>,
+    /// Name of the Inventory List. Supports %LIKE% syntax
+    #[builder(default, setter(into))]
+    pub inventory_list_name: Option<String>,
+    /// The Advertiser the Inventory List may be used by
+    #[builder(default, setter(into))]
+    pub advertiser_id: Option<u64>,
+    /// An alternative id to lookup the Inventory List, if desired
+    #[builder(default, setter(into))]
+    pub alternative_id: Option<String>,
+    /// Is the Inventory List active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Read<InventoryList> for ReadInventoryList {}
+
+impl PartialEq<InventoryList> for ReadInventoryList {
+    fn eq(&self, other: &InventoryList) -> bool {
+        (self.inventory_list_id.is_none()
+            || self.inventory_list_id == Some(other.inventory_list_id))
+            && (self.inventory_list_name.is_none()
+                || self.inventory_list_name.as_ref() == Some(&other.inventory_list_name))
+            && (self.advertiser_id.is_none() || self.advertiser_id == other.advertiser_id)
+            && (self.alternative_id.is_none() || self.alternative_id == other.alternative_id)
+            && (self.active.is_none() || self.active == other.active)
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct CreateInventoryList {
+    /// Name of the Inventory List, e.g. "Premium placements"
+    #[builder(setter(into))]
+    pub inventory_list_name: String,
+
+    /// Placements in the list as site_id/placement_id, e.g. "12345/top_banner"
+    #[builder(default)]
+    #[serde(serialize_with = "list_item::serialize_items")]
+    pub placements: Vec<String>,
+
+    /// The Advertiser the Inventory List may be used by. If empty, every Advertiser may use the list.
+    #[builder(default, setter(into))]
+    pub advertiser_id: Option<u64>,
+
+    /// An alternative id to lookup the Inventory List, if desired
+    #[builder(default, setter(into))]
+    pub alternative_id: Option<String>,
+
+    /// Notes about the Inventory List, up to 255 chars
+    #[builder(default, setter(into))]
+    pub notes: Option<String>,
+
+    /// Is the Inventory List active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Create<InventoryList> for CreateInventoryList {
+    fn into_resource(self, inventory_list_id: u64) -> InventoryList {
+        InventoryList {
+            inventory_list_id,
+            inventory_list_name: self.inventory_list_name,
+            placements: list_item::normalized_items(self.placements, normalize_item),
+            advertiser_id: self.advertiser_id,
+            alternative_id: self.alternative_id,
+            notes: self.notes,
+            active: self.active,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TypedBuilder)]
+pub struct DeleteInventoryList {
+    inventory_list_id: u64,
+}
+
+impl Delete<InventoryList> for DeleteInventoryList {}
+
+impl Delete<InventoryList> for InventoryList {}
+
+/// Replace the placements of an Inventory List with those in a file, one placement per line
+#[derive(Clone, Debug, TypedBuilder)]
+pub struct UploadInventoryList {
+    /// Unique ID of the Inventory List the file replaces the placements of
+    pub inventory_list_id: u64,
+    /// Name of the file, e.g. "placements.txt"
+    #[builder(setter(into))]
+    pub file_name: String,
+    /// Raw content of the file
+    pub content: Vec<u8>,
+}
+
+list_items!(
+    InventoryList {
+        placements,
+        normalize_item,
+        add_placements,
+        remove_placements
+    },
+    UploadInventoryList {
+        inventory_list_id,
+        from_placements,
+        placements
+    }
+);
@@ -2,10 +2,15 @@
 //! Items belong to a single Campaign, may be associated with one or more Creatives using the
 //! Creative Line Item Association, and are associated with a single Targeting Template.
 
+use crate::client::{async_client::impl_async_clients, sync_client::SyncClient};
 use crate::resource::{
     common::{BiddingStratergy, FrequencyCap, RevenueType, WeightingMethod},
+    line_item_flight::{
+        validate_schedule, CreateLineItemFlight, LineItemFlight, ReadLineItemFlight,
+    },
     Create, Delete, Read, Resource,
 };
+use crate::Result;
 use serde::{Deserialize, Serialize};
 use typed_builder::TypedBuilder;
 
@@ -73,8 +78,9 @@ pub struct LineItem {
     currency: String,
     // ToDo: Unknown Type
     // pacing: null,
-    // ToDo: Unknown Type
-    // test_plan_id: null,
+    /// ID of the Test Plan set on the Campaign, if any. Buzz copies it from the Campaign so it is
+    /// read only, set test_plan_id on the Campaign and test_group_id on the Line Item instead.
+    test_plan_id: Option<u64>,
     push_status: u64,
     push_update: bool,
     account_id: u64,
@@ -151,7 +157,7 @@ impl LineItem {
     /// # }
     /// ```
     pub fn read_builder(
-    ) -> ReadLineItemBuilder<((), (), (), (), (), (), (), (), (), (), (), (), ())> {
+    ) -> ReadLineItemBuilder<((), (), (), (), (), (), (), (), (), (), (), (), (), ())> {
         ReadLineItem::builder()
     }
 
@@ -175,6 +181,158 @@ impl LineItem {
     pub fn delete_builder() -> DeleteLineItemBuilder<((),)> {
         DeleteLineItem::builder()
     }
+
+    /// ID of the Test Plan set on the Campaign, if any
+    pub fn test_plan_id(&self) -> Option<u64> {
+        self.test_plan_id
+    }
+
+    /// ID of the Test Group of the Campaign's Test Plan the Line Item serves to, if any
+    pub fn test_group_id(&self) -> Option<u64> {
+        self.test_group_id
+    }
+
+    /// Check a flight schedule could replace the flights of this Line Item
+    pub fn validate_flights(&self, flights: &[CreateLineItemFlight]) -> Result<()> {
+        validate_schedule(
+            self.line_item_id,
+            &self.start_date,
+            self.end_date.as_deref(),
+            flights,
+        )
+    }
+
+    fn read_flights(&self) -> ReadLineItemFlight {
+        ReadLineItemFlight {
+            line_item_id: Some(self.line_item_id),
+            ..Default::default()
+        }
+    }
+
+    /// Replace the flights of this Line Item with a new schedule. The schedule is validated before
+    /// any changes are made. Buzz has no transactions so if any request fails part way through,
+    /// the new flights are removed and the previous schedule is recreated before the error is
+    /// returned.
+    pub fn replace_flights_sync<C: SyncClient>(
+        &self,
+        beeswax_client: &C,
+        flights: &[CreateLineItemFlight],
+    ) -> Result<Vec<LineItemFlight>> {
+        self.validate_flights(flights)?;
+        let existing = self.read_flights().read_sync(beeswax_client)?;
+
+        let mut deleted = Vec::new();
+        let mut created = Vec::new();
+        let mut outcome = Ok(());
+        for flight in &existing {
+            outcome = flight.delete_sync(beeswax_client);
+            if outcome.is_err() {
+                break;
+            }
+            deleted.push(flight);
+        }
+        if outcome.is_ok() {
+            for flight in flights {
+                match flight.create_sync(beeswax_client) {
+                    Ok(flight) => created.push(flight),
+                    Err(error) => {
+                        outcome = Err(error);
+                        break;
+                    }
+                }
+            }
+        }
+
+        match outcome {
+            Ok(()) => Ok(created),
+            Err(error) => {
+                let mut restore_errors = Vec::new();
+                for flight in &created {
+                    if let Err(error) = flight.delete_sync(beeswax_client) {
+                        restore_errors.push(error.to_string());
+                    }
+                }
+                for flight in deleted {
+                    if let Err(error) =
+                        CreateLineItemFlight::from(flight).create_sync(beeswax_client)
+                    {
+                        restore_errors.push(error.to_string());
+                    }
+                }
+                Err(replace_flights_error(error, restore_errors))
+            }
+        }
+    }
+}
+
+fn replace_flights_error(
+    error: Box<dyn std::error::Error>,
+    restore_errors: Vec<String>,
+) -> Box<dyn std::error::Error> {
+    if restore_errors.is_empty() {
+        error
+    } else {
+        format!(
+            "{}, restoring the previous flights also failed: {}",
+            error,
+            restore_errors.join(", ")
+        )
+        .into()
+    }
+}
+
+impl_async_clients! {
+    /// Replace the flights of a Line Item with a new schedule. The schedule is validated before any
+    /// changes are made. Buzz has no transactions so if any request fails part way through, the
+    /// new flights are removed and the previous schedule is recreated before the error is returned.
+    pub async fn replace_line_item_flights(
+        &self,
+        line_item: &LineItem,
+        flights: &[CreateLineItemFlight],
+    ) -> Result<Vec<LineItemFlight>> {
+        line_item.validate_flights(flights)?;
+        let existing = self.read(&line_item.read_flights()).await?;
+
+        let mut deleted = Vec::new();
+        let mut created = Vec::new();
+        let mut outcome = Ok(());
+        for flight in &existing {
+            outcome = self.delete(flight).await;
+            if outcome.is_err() {
+                break;
+            }
+            deleted.push(flight);
+        }
+        if outcome.is_ok() {
+            for flight in flights {
+                match self.create(flight).await {
+                    Ok(flight) => created.push(flight),
+                    Err(error) => {
+                        outcome = Err(error);
+                        break;
+                    }
+                }
+            }
+        }
+
+        match outcome {
+            Ok(()) => Ok(created),
+            Err(error) => {
+                let mut restore_errors = Vec::new();
+                for flight in &created {
+                    if let Err(error) = self.delete(flight).await {
+                        restore_errors.push(error.to_string());
+                    }
+                }
+                for flight in deleted {
+                    if let Err(error) = self.create(&CreateLineItemFlight::from(flight)).await {
+                        restore_errors.push(error.to_string());
+                    }
+                }
+                Err(replace_flights_error(error, restore_errors))
+            }
+        }
+    }
 }
 
 impl Resource for LineItem {
@@ -204,6 +362,9 @@ pub struct ReadLineItem {
     /// ID of the Delivery Modifier associated with this Line Item
     #[builder(default, setter(into))]
     delivery_modifier_id: Option<u64>,
+    /// ID of the Test Plan set on the Line Item's Campaign
+    #[builder(default, setter(into))]
+    test_plan_id: Option<u64>,
     /// Start date of the Line Item. No Line Items associated with the Campaign can have start dates prior to this date.
     #[builder(default, setter(into))]
     start_date: Option<String>,
@@ -235,6 +396,7 @@ impl PartialEq<LineItem> for ReadLineItem {
             && (self.bid_modifier_id.is_none() || self.bid_modifier_id == other.bid_modifier_id)
             && (self.delivery_modifier_id.is_none()
                 || self.delivery_modifier_id == other.delivery_modifier_id)
+            && (self.test_plan_id.is_none() || self.test_plan_id == other.test_plan_id)
             && (self.start_date.is_none() || self.start_date.as_ref() == Some(&other.start_date))
             && (self.end_date.is_none() || self.end_date == other.end_date)
             && (self.alternative_id.is_none() || self.alternative_id == other.alternative_id)
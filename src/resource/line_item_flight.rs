@@ -0,0 +1,287 @@
+//! A Line Item Flight splits the schedule of a Line Item into periods, each with its own budget.
+//! Flights are required to use the flight pacing type of a Line Item's bidding strategy, in which
+//! case every flight must include a budget. Flights of the same Line Item may not overlap.
+
+use crate::resource::{Create, Delete, Read, Resource};
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct LineItemFlight {
+    /// Unique ID of the Line Item Flight
+    pub line_item_flight_id: u64,
+
+    /// Must be a valid and active Line Item
+    pub line_item_id: u64,
+
+    /// Start date of the flight. Must not be before the start date of the Line Item.
+    pub start_date: String,
+
+    /// End date of the flight. Must not be after the end date of the Line Item.
+    pub end_date: String,
+
+    /// Maximum amount to spend or deliver during the flight. Required when the Line Item uses
+    /// flight pacing.
+    pub budget: Option<f64>,
+
+    /// Is the flight active?
+    pub active: Option<bool>,
+
+    // Undocumented
+    pub account_id: Option<u64>,
+    pub create_date: Option<String>,
+    pub update_date: Option<String>,
+}
+
+impl LineItemFlight {
+    /// Create a builder for CreateLineItemFlight
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::LineItemFlight;
+    ///
+    /// let create_line_item_flight = LineItemFlight::create_builder()
+    ///     .line_item_id(1)
+    ///     .start_date("2020-01-01 00:00:00")
+    ///     .end_date("2020-01-31 23:59:59")
+    ///     .budget(1000.0)
+    ///     .build();
+    ///
+    /// let line_item_flight = beeswax_client.create(&create_line_item_flight).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_builder() -> CreateLineItemFlightBuilder<((), (), (), (), ())> {
+        CreateLineItemFlight::builder()
+    }
+
+    /// Create a builder for ReadLineItemFlight
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::LineItemFlight;
+    ///
+    /// let read_line_item_flight = LineItemFlight::read_builder()
+    ///     .line_item_id(1)
+    ///     .build();
+    ///
+    /// let line_item_flights = beeswax_client.read(&read_line_item_flight).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadLineItemFlightBuilder<((), (), ())> {
+        ReadLineItemFlight::builder()
+    }
+
+    /// Create a builder for DeleteLineItemFlight
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::LineItemFlight;
+    ///
+    /// let delete_line_item_flight = LineItemFlight::delete_builder()
+    ///     .line_item_flight_id(10)
+    ///     .build();
+    ///
+    /// beeswax_client.delete(&delete_line_item_flight).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_builder() -> DeleteLineItemFlightBuilder<((),)> {
+        DeleteLineItemFlight::builder()
+    }
+}
+
+impl Resource for LineItemFlight {
+    const NAME: &'static str = "line_item_flight";
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadLineItemFlight {
+    /// Unique ID of the Line Item Flight
+    #[builder(default, setter(into))]
+    pub line_item_flight_id: Option<u64>,
+    /// Unique ID of the Line Item the flight belongs to
+    #[builder(default, setter(into))]
+    pub line_item_id: Option<u64>,
+    /// Is the flight active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Read<LineItemFlight> for ReadLineItemFlight {}
+
+impl PartialEq<LineItemFlight> for ReadLineItemFlight {
+    fn eq(&self, other: &LineItemFlight) -> bool {
+        (self.line_item_flight_id.is_none()
+            || self.line_item_flight_id == Some(other.line_item_flight_id))
+            && (self.line_item_id.is_none() || self.line_item_id == Some(other.line_item_id))
+            && (self.active.is_none() || self.active == other.active)
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct CreateLineItemFlight {
+    /// Must be a valid and active Line Item
+    pub line_item_id: u64,
+
+    /// Start date of the flight. Must not be before the start date of the Line Item.
+    #[builder(setter(into))]
+    pub start_date: String,
+
+    /// End date of the flight. Must not be after the end date of the Line Item.
+    #[builder(setter(into))]
+    pub end_date: String,
+
+    /// Maximum amount to spend or deliver during the flight. Required when the Line Item uses
+    /// flight pacing.
+    #[builder(default, setter(into))]
+    pub budget: Option<f64>,
+
+    /// Is the flight active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Create<LineItemFlight> for CreateLineItemFlight {
+    fn into_resource(self, line_item_flight_id: u64) -> LineItemFlight {
+        LineItemFlight {
+            line_item_flight_id,
+            line_item_id: self.line_item_id,
+            start_date: self.start_date,
+            end_date: self.end_date,
+            budget: self.budget,
+            active: self.active,
+            ..Default::default()
+        }
+    }
+}
+
+/// Recreate an existing flight, used to restore a schedule when replacing it fails
+impl From<&LineItemFlight> for CreateLineItemFlight {
+    fn from(line_item_flight: &LineItemFlight) -> Self {
+        CreateLineItemFlight {
+            line_item_id: line_item_flight.line_item_id,
+            start_date: line_item_flight.start_date.clone(),
+            end_date: line_item_flight.end_date.clone(),
+            budget: line_item_flight.budget,
+            active: line_item_flight.active,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TypedBuilder)]
+pub struct DeleteLineItemFlight {
+    line_item_flight_id: u64,
+}
+
+impl Delete<LineItemFlight> for DeleteLineItemFlight {}
+
+impl Delete<LineItemFlight> for LineItemFlight {}
+
+/// A date parsed into its year, month, day, hour, minute and second, in that order so dates can be
+/// compared directly
+type DateTime = [u32; 6];
+
+/// Parse a date in either of the forms Buzz accepts, "2020-01-31 23:59:59" or "2020-01-31". A date
+/// without a time is the start of the day, or the end of the day when `end_of_day` is set.
+fn parse_date(date: &str, end_of_day: bool) -> Result<DateTime> {
+    let invalid = || format!("invalid date {}", date);
+    let (day, time) = match date.trim().find(' ') {
+        Some(index) => (&date.trim()[..index], Some(date.trim()[index + 1..].trim())),
+        None => (date.trim(), None),
+    };
+    let mut parts = Vec::with_capacity(6);
+    for part in day.split('-') {
+        parts.push(part.parse::<u32>().map_err(|_| invalid())?);
+    }
+    match time {
+        Some(time) => {
+            for part in time.split(':') {
+                parts.push(part.parse::<u32>().map_err(|_| invalid())?);
+            }
+        }
+        None if end_of_day => parts.extend_from_slice(&[23, 59, 59]),
+        None => parts.extend_from_slice(&[0, 0, 0]),
+    }
+    if parts.len() != 6 {
+        return Err(invalid().into());
+    }
+    let mut date_time = DateTime::default();
+    date_time.copy_from_slice(&parts);
+    Ok(date_time)
+}
+
+/// Check a flight schedule is valid for a Line Item before sending it to Buzz. Every flight must
+/// belong to the Line Item, end after it starts, fall within the Line Item's dates and not overlap
+/// any other flight. Dates may be given with or without a time, a date without a time covers the
+/// whole day.
+pub(crate) fn validate_schedule(
+    line_item_id: u64,
+    line_item_start_date: &str,
+    line_item_end_date: Option<&str>,
+    flights: &[CreateLineItemFlight],
+) -> Result<()> {
+    let line_item_start = parse_date(line_item_start_date, false)?;
+    let line_item_end = line_item_end_date
+        .map(|end_date| parse_date(end_date, true))
+        .transpose()?;
+
+    let mut sorted = Vec::with_capacity(flights.len());
+    for flight in flights {
+        let start = parse_date(&flight.start_date, false)?;
+        let end = parse_date(&flight.end_date, true)?;
+        sorted.push((start, end, flight));
+    }
+    sorted.sort_by_key(|(start, _, _)| *start);
+
+    for (start, end, flight) in &sorted {
+        if flight.line_item_id != line_item_id {
+            return Err(format!(
+                "flight {} - {} belongs to line item {} not {}",
+                flight.start_date, flight.end_date, flight.line_item_id, line_item_id
+            )
+            .into());
+        }
+        if end <= start {
+            return Err(format!(
+                "flight {} - {} ends before it starts",
+                flight.start_date, flight.end_date
+            )
+            .into());
+        }
+        let after_end = line_item_end
+            .map(|line_item_end| *end > line_item_end)
+            .unwrap_or(false);
+        if *start < line_item_start || after_end {
+            return Err(format!(
+                "flight {} - {} is outside of the line item's dates",
+                flight.start_date, flight.end_date
+            )
+            .into());
+        }
+    }
+
+    for pair in sorted.windows(2) {
+        let ((_, end, first), (start, _, second)) = (&pair[0], &pair[1]);
+        if start <= end {
+            return Err(format!(
+                "flight {} - {} overlaps flight {} - {}",
+                first.start_date, first.end_date, second.start_date, second.end_date
+            )
+            .into());
+        }
+    }
+
+    Ok(())
+}
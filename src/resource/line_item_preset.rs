@@ -0,0 +1,371 @@
+//! A Line Item Preset holds default values for new Line Items, such as the bidding strategy,
+//! frequency cap and Targeting Template, so house defaults can be kept in one place. Advertisers
+//! may set a preset to use by default with default_line_item_preset_id. Use create_line_item to
+//! start a CreateLineItem from the preset.
+
+use crate::resource::{
+    common::{BiddingStratergy, FrequencyCap, RevenueType, WeightingMethod},
+    line_item::CreateLineItem,
+    Advertiser, Create, Delete, Read, Resource,
+};
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct LineItemPreset {
+    /// Unique ID of the Line Item Preset
+    pub line_item_preset_id: u64,
+
+    /// Name of the Line Item Preset, e.g. "House defaults"
+    pub line_item_preset_name: String,
+
+    /// The Advertiser the preset may be used by. If empty, every Advertiser may use the preset.
+    pub advertiser_id: Option<u64>,
+
+    /// The type of each Line Item. 0=banner, 1=video, 2=native
+    pub line_item_type_id: u64,
+
+    /// The ID of the Targeting Template to associate with each Line Item
+    pub targeting_template_id: Option<u64>,
+
+    /// Maximum amount to spend or deliver in a day
+    pub daily_budget: Option<f64>,
+
+    /// Type of budget, 0=spend, 1=impressions, 2=spend with vendor fees
+    pub budget_type: Option<u64>,
+
+    /// Supported revenue types: CPM, CPC, CPCV, CPI, CPA
+    pub revenue_type: Option<RevenueType>,
+
+    /// The basis of calculation for the revenue_type
+    pub revenue_amount: Option<f64>,
+
+    /// ID of a Bid Modifier object to associate with each Line Item
+    pub bid_modifier_id: Option<u64>,
+
+    /// ID of the Delivery Modifier to associate with each Line Item
+    pub delivery_modifier_id: Option<u64>,
+
+    /// Maximum bid after taking into consideration any Bid Modifiers
+    pub max_bid: Option<f64>,
+
+    /// Bidding Strategy JSON
+    pub bidding: Option<BiddingStratergy>,
+
+    /// Either RANDOM or WEIGHTED
+    pub creative_weighting_method: Option<WeightingMethod>,
+
+    /// Frequency cap JSON
+    pub frequency_cap: Option<Vec<FrequencyCap>>,
+
+    /// The method of frequency capping
+    pub frequency_cap_type: Option<u64>,
+
+    /// Timezones each Line Item is expected to serve within
+    pub user_timezones: Option<Vec<String>>,
+
+    /// Notes about the Line Item Preset, up to 255 chars
+    pub notes: Option<String>,
+
+    /// Is the Line Item Preset active?
+    pub active: Option<bool>,
+
+    // Undocumented
+    pub account_id: Option<u64>,
+    pub create_date: Option<String>,
+    pub update_date: Option<String>,
+}
+
+impl LineItemPreset {
+    /// Create a builder for CreateLineItemPreset
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::LineItemPreset;
+    ///
+    /// let create_line_item_preset = LineItemPreset::create_builder()
+    ///     .line_item_preset_name("House defaults")
+    ///     .line_item_type_id(0)
+    ///     .max_bid(5.0)
+    ///     .build();
+    ///
+    /// let line_item_preset = beeswax_client.create(&create_line_item_preset).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn create_builder() -> CreateLineItemPresetBuilder<(
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+        (),
+    )> {
+        CreateLineItemPreset::builder()
+    }
+
+    /// Create a builder for ReadLineItemPreset
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::LineItemPreset;
+    ///
+    /// let read_line_item_preset = LineItemPreset::read_builder()
+    ///     .line_item_preset_name("House defaults".to_string())
+    ///     .build();
+    ///
+    /// let line_item_presets = beeswax_client.read(&read_line_item_preset).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadLineItemPresetBuilder<((), (), (), (), ())> {
+        ReadLineItemPreset::builder()
+    }
+
+    /// Create a builder for DeleteLineItemPreset
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::LineItemPreset;
+    ///
+    /// let delete_line_item_preset = LineItemPreset::delete_builder()
+    ///     .line_item_preset_id(10)
+    ///     .build();
+    ///
+    /// beeswax_client.delete(&delete_line_item_preset).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_builder() -> DeleteLineItemPresetBuilder<((),)> {
+        DeleteLineItemPreset::builder()
+    }
+
+    /// Start a Line Item for the Campaign with the preset's values filled in. The fields Buzz
+    /// requires are always given, the same as for CampaignPreset::create_campaign. Any field can be
+    /// changed before creating it.
+    /// ```
+    /// use beeswax::resource::LineItemPreset;
+    ///
+    /// let line_item_preset = LineItemPreset {
+    ///     max_bid: Some(5.0),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let create_line_item =
+    ///     line_item_preset.create_line_item(1, 2, "Retargeting", 1000.0, "2020-01-01 00:00:00");
+    /// assert_eq!(create_line_item.max_bid, Some(5.0));
+    /// ```
+    pub fn create_line_item<N: Into<String>, D: Into<String>>(
+        &self,
+        advertiser_id: u64,
+        campaign_id: u64,
+        line_item_name: N,
+        line_item_budget: f64,
+        start_date: D,
+    ) -> CreateLineItem {
+        CreateLineItem {
+            campaign_id,
+            advertiser_id,
+            line_item_name: line_item_name.into(),
+            start_date: start_date.into(),
+            line_item_type_id: self.line_item_type_id,
+            targeting_template_id: self.targeting_template_id,
+            line_item_budget,
+            daily_budget: self.daily_budget,
+            budget_type: self.budget_type,
+            revenue_type: self.revenue_type.clone(),
+            revenue_amount: self.revenue_amount,
+            bid_modifier_id: self.bid_modifier_id,
+            delivery_modifier_id: self.delivery_modifier_id,
+            max_bid: self.max_bid,
+            bidding: self.bidding.clone().unwrap_or_default(),
+            creative_weighting_method: self.creative_weighting_method.clone(),
+            frequency_cap: self.frequency_cap.clone(),
+            frequency_cap_type: self.frequency_cap_type,
+            user_timezones: self.user_timezones.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+impl Resource for LineItemPreset {
+    const NAME: &'static str = "line_item_preset";
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadLineItemPreset {
+    /// Unique ID of the Line Item Preset
+    #[builder(default, setter(into))]
+    pub line_item_preset_id: Option<u64>,
+    /// Name of the Line Item Preset. Supports %LIKE% syntax
+    #[builder(default, setter(into))]
+    pub line_item_preset_name: Option<String>,
+    /// The Advertiser the preset may be used by
+    #[builder(default, setter(into))]
+    pub advertiser_id: Option<u64>,
+    /// The type of Line Item the preset is for
+    #[builder(default, setter(into))]
+    pub line_item_type_id: Option<u64>,
+    /// Is the Line Item Preset active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Read<LineItemPreset> for ReadLineItemPreset {}
+
+impl PartialEq<LineItemPreset> for ReadLineItemPreset {
+    fn eq(&self, other: &LineItemPreset) -> bool {
+        (self.line_item_preset_id.is_none()
+            || self.line_item_preset_id == Some(other.line_item_preset_id))
+            && (self.line_item_preset_name.is_none()
+                || self.line_item_preset_name.as_ref() == Some(&other.line_item_preset_name))
+            && (self.advertiser_id.is_none() || self.advertiser_id == other.advertiser_id)
+            && (self.line_item_type_id.is_none()
+                || self.line_item_type_id == Some(other.line_item_type_id))
+            && (self.active.is_none() || self.active == other.active)
+    }
+}
+
+impl ReadLineItemPreset {
+    /// Search criteria for the Advertiser's default Line Item Preset, if it has one
+    pub fn advertiser_default(advertiser: &Advertiser) -> Option<ReadLineItemPreset> {
+        advertiser
+            .default_line_item_preset_id
+            .map(|line_item_preset_id| ReadLineItemPreset {
+                line_item_preset_id: Some(line_item_preset_id),
+                ..Default::default()
+            })
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct CreateLineItemPreset {
+    /// Name of the Line Item Preset, e.g. "House defaults"
+    #[builder(setter(into))]
+    pub line_item_preset_name: String,
+
+    /// The Advertiser the preset may be used by. If empty, every Advertiser may use the preset.
+    #[builder(default, setter(into))]
+    pub advertiser_id: Option<u64>,
+
+    /// The type of each Line Item. 0=banner, 1=video, 2=native
+    pub line_item_type_id: u64,
+
+    /// The ID of the Targeting Template to associate with each Line Item
+    #[builder(default, setter(into))]
+    pub targeting_template_id: Option<u64>,
+
+    /// Maximum amount to spend or deliver in a day
+    #[builder(default, setter(into))]
+    pub daily_budget: Option<f64>,
+
+    /// Type of budget, 0=spend, 1=impressions, 2=spend with vendor fees
+    #[builder(default, setter(into))]
+    pub budget_type: Option<u64>,
+
+    /// Supported revenue types: CPM, CPC, CPCV, CPI, CPA
+    #[builder(default, setter(into))]
+    pub revenue_type: Option<RevenueType>,
+
+    /// The basis of calculation for the revenue_type
+    #[builder(default, setter(into))]
+    pub revenue_amount: Option<f64>,
+
+    /// ID of a Bid Modifier object to associate with each Line Item
+    #[builder(default, setter(into))]
+    pub bid_modifier_id: Option<u64>,
+
+    /// ID of the Delivery Modifier to associate with each Line Item
+    #[builder(default, setter(into))]
+    pub delivery_modifier_id: Option<u64>,
+
+    /// Maximum bid after taking into consideration any Bid Modifiers
+    #[builder(default, setter(into))]
+    pub max_bid: Option<f64>,
+
+    /// Bidding Strategy JSON
+    #[builder(default, setter(into))]
+    pub bidding: Option<BiddingStratergy>,
+
+    /// Either RANDOM or WEIGHTED
+    #[builder(default, setter(into))]
+    pub creative_weighting_method: Option<WeightingMethod>,
+
+    /// Frequency cap JSON
+    #[builder(default, setter(into))]
+    pub frequency_cap: Option<Vec<FrequencyCap>>,
+
+    /// The method of frequency capping
+    #[builder(default, setter(into))]
+    pub frequency_cap_type: Option<u64>,
+
+    /// Timezones each Line Item is expected to serve within
+    #[builder(default, setter(into))]
+    pub user_timezones: Option<Vec<String>>,
+
+    /// Notes about the Line Item Preset, up to 255 chars
+    #[builder(default, setter(into))]
+    pub notes: Option<String>,
+
+    /// Is the Line Item Preset active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Create<LineItemPreset> for CreateLineItemPreset {
+    fn into_resource(self, line_item_preset_id: u64) -> LineItemPreset {
+        LineItemPreset {
+            line_item_preset_id,
+            line_item_preset_name: self.line_item_preset_name,
+            advertiser_id: self.advertiser_id,
+            line_item_type_id: self.line_item_type_id,
+            targeting_template_id: self.targeting_template_id,
+            daily_budget: self.daily_budget,
+            budget_type: self.budget_type,
+            revenue_type: self.revenue_type,
+            revenue_amount: self.revenue_amount,
+            bid_modifier_id: self.bid_modifier_id,
+            delivery_modifier_id: self.delivery_modifier_id,
+            max_bid: self.max_bid,
+            bidding: self.bidding,
+            creative_weighting_method: self.creative_weighting_method,
+            frequency_cap: self.frequency_cap,
+            frequency_cap_type: self.frequency_cap_type,
+            user_timezones: self.user_timezones,
+            notes: self.notes,
+            active: self.active,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TypedBuilder)]
+pub struct DeleteLineItemPreset {
+    line_item_preset_id: u64,
+}
+
+impl Delete<LineItemPreset> for DeleteLineItemPreset {}
+
+impl Delete<LineItemPreset> for LineItemPreset {}
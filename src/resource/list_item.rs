@@ -0,0 +1,225 @@
+//! Helpers shared by the list resources (DomainList, AppList and InventoryList), which hold a
+//! large set of string items edited in bulk or uploaded from a file with one item per line.
+
+use crate::Result;
+use serde::Serializer;
+use std::{collections::HashSet, fs, path::Path};
+
+/// Add each normalized item that is not already in the list, keeping the existing order
+pub(crate) fn add_items<I, S>(items: &mut Vec<String>, new_items: I, normalize: fn(&str) -> String)
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut seen: HashSet<String> = items.iter().cloned().collect();
+    for item in new_items {
+        let item = normalize(item.as_ref());
+        if !item.is_empty() && seen.insert(item.clone()) {
+            items.push(item);
+        }
+    }
+}
+
+/// The normalized items without duplicates, keeping their order
+pub(crate) fn normalized_items<I, S>(items: I, normalize: fn(&str) -> String) -> Vec<String>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut normalized = Vec::new();
+    add_items(&mut normalized, items, normalize);
+    normalized
+}
+
+/// Remove every item matching one of the normalized items
+pub(crate) fn remove_items<I, S>(
+    items: &mut Vec<String>,
+    old_items: I,
+    normalize: fn(&str) -> String,
+) where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let old_items: HashSet<String> = old_items
+        .into_iter()
+        .map(|item| normalize(item.as_ref()))
+        .collect();
+    items.retain(|item| !old_items.contains(item));
+}
+
+/// Parse a list file into its items, one per line. Blank lines and lines starting with # are
+/// skipped.
+pub(crate) fn parse_items(content: &[u8]) -> Result<Vec<String>> {
+    let content = std::str::from_utf8(content)?;
+    Ok(content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(str::to_string)
+        .collect())
+}
+
+/// Write items into a list file, one per line
+pub(crate) fn file_content<I, S>(items: I) -> Vec<u8>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    let mut content = Vec::new();
+    for item in items {
+        content.extend_from_slice(item.as_ref().as_bytes());
+        content.push(b'\n');
+    }
+    content
+}
+
+/// Read a list file from disk, returning its file name and content
+pub(crate) fn read_file(path: &Path) -> Result<(String, Vec<u8>)> {
+    let file_name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or("list path has no file name")?
+        .to_string();
+    Ok((file_name, fs::read(path)?))
+}
+
+/// Domains are matched case insensitively, without a scheme or trailing slash
+pub(crate) fn normalize_domain(domain: &str) -> String {
+    let domain = domain.trim().to_lowercase();
+    let domain = domain
+        .trim_start_matches("https://")
+        .trim_start_matches("http://");
+    domain.trim_end_matches('/').to_string()
+}
+
+/// Other items are only trimmed
+pub(crate) fn normalize_item(item: &str) -> String {
+    item.trim().to_string()
+}
+
+/// Serialize domains the way they are stored, so Buzz receives the same list as add_items builds
+pub(crate) fn serialize_domains<S: Serializer>(
+    domains: &[String],
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    serializer.collect_seq(normalized_items(domains, normalize_domain))
+}
+
+/// Serialize items the way they are stored, so Buzz receives the same list as add_items builds
+pub(crate) fn serialize_items<S: Serializer>(
+    items: &[String],
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    serializer.collect_seq(normalized_items(items, normalize_item))
+}
+
+/// Implement the item editing and file upload shared by the list resources. Takes the list with
+/// its items field, how items are normalized and the names of its add and remove methods, then the
+/// upload with its list ID field and the names of its methods to write and read items.
+macro_rules! list_items {
+    (
+        $list:ident { $items:ident, $normalize:ident, $add:ident, $remove:ident },
+        $upload:ident { $id:ident, $from_items:ident, $file_items:ident }
+    ) => {
+        impl $list {
+            /// Is the item in the list? The item is normalized the same way as the list's items.
+            pub fn contains(&self, item: &str) -> bool {
+                self.$items
+                    .contains(&crate::resource::list_item::$normalize(item))
+            }
+
+            /// Add the items not already in the list. Update the list to save the change.
+            pub fn $add<I, S>(&mut self, items: I)
+            where
+                I: IntoIterator<Item = S>,
+                S: AsRef<str>,
+            {
+                crate::resource::list_item::add_items(
+                    &mut self.$items,
+                    items,
+                    crate::resource::list_item::$normalize,
+                );
+            }
+
+            /// Remove the items from the list. Update the list to save the change.
+            pub fn $remove<I, S>(&mut self, items: I)
+            where
+                I: IntoIterator<Item = S>,
+                S: AsRef<str>,
+            {
+                crate::resource::list_item::remove_items(
+                    &mut self.$items,
+                    items,
+                    crate::resource::list_item::$normalize,
+                );
+            }
+        }
+
+        impl $upload {
+            /// Read a file from disk ready to upload to the given list
+            pub fn from_path<P: AsRef<std::path::Path>>(
+                $id: u64,
+                path: P,
+            ) -> crate::Result<$upload> {
+                let (file_name, content) = crate::resource::list_item::read_file(path.as_ref())?;
+                Ok($upload {
+                    $id,
+                    file_name,
+                    content,
+                })
+            }
+
+            /// Write the items into a file ready to upload to the given list
+            pub fn $from_items<I, S>($id: u64, items: I) -> $upload
+            where
+                I: IntoIterator<Item = S>,
+                S: AsRef<str>,
+            {
+                $upload {
+                    $id,
+                    file_name: format!(
+                        "{}_{}.txt",
+                        <$list as crate::resource::Resource>::NAME,
+                        $id
+                    ),
+                    content: crate::resource::list_item::file_content(items),
+                }
+            }
+
+            /// The items in the file, skipping blank lines and # comments
+            pub fn $file_items(&self) -> crate::Result<Vec<String>> {
+                Ok(crate::resource::list_item::normalized_items(
+                    crate::resource::list_item::parse_items(&self.content)?,
+                    crate::resource::list_item::$normalize,
+                ))
+            }
+        }
+
+        impl crate::resource::Upload<$list> for $upload {
+            // Buzz reads uploaded files from the creative_content field whatever the resource, as
+            // for Video Asset uploads
+            const FIELD_NAME: &'static str = "creative_content";
+
+            fn upload_id(&self) -> u64 {
+                self.$id
+            }
+
+            fn file_name(&self) -> &str {
+                &self.file_name
+            }
+
+            fn file_content(&self) -> &[u8] {
+                &self.content
+            }
+
+            /// Buzz replaces every item in the list with those in the file, or rejects the upload
+            /// if the file can't be read
+            fn upload_into(&self, list: &mut $list) -> crate::Result<()> {
+                list.$items = self.$file_items()?;
+                Ok(())
+            }
+        }
+    };
+}
+
+pub(crate) use list_items;
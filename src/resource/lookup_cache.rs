@@ -0,0 +1,195 @@
+//! A cache of the static lookup Views used to validate requests before they're sent to Buzz. The
+//! countries, creative_sizes, currency and mime_types Views rarely change, so they are fetched
+//! once and kept until the cache's TTL runs out. The cache can be saved to disk and loaded again
+//! to avoid fetching the Views every time a process starts.
+
+use crate::client::{async_client::impl_async_clients, sync_client::SyncClient};
+use crate::resource::{
+    common::ViewName,
+    view_row::{Country, CreativeSize, Currency, MimeType},
+};
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The rows of each cached View
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct LookupTables {
+    pub countries: Vec<Country>,
+    pub creative_sizes: Vec<CreativeSize>,
+    pub currencies: Vec<Currency>,
+    pub mime_types: Vec<MimeType>,
+}
+
+/// What gets written to disk, with the fetch time as seconds since the epoch
+#[derive(Deserialize, Serialize)]
+struct SavedLookups {
+    fetched_at: u64,
+    tables: LookupTables,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct LookupCache {
+    ttl: Duration,
+    fetched_at: Option<SystemTime>,
+    tables: LookupTables,
+}
+
+impl LookupCache {
+    /// Create an empty cache which keeps the Views for ttl once fetched
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::sync_client::SyncInMemoryClient;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # let beeswax_client = SyncInMemoryClient::new();
+    /// use beeswax::resource::lookup_cache::LookupCache;
+    /// use std::time::Duration;
+    ///
+    /// let mut lookup_cache = LookupCache::new(Duration::from_secs(24 * 60 * 60));
+    /// lookup_cache.refresh_if_stale_sync(&beeswax_client)?;
+    ///
+    /// if !lookup_cache.is_valid_creative_size(300, 250) {
+    ///     println!("300x250 is not a supported size");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(ttl: Duration) -> LookupCache {
+        LookupCache {
+            ttl,
+            fetched_at: None,
+            tables: LookupTables::default(),
+        }
+    }
+
+    /// Load a cache saved with save. The cache keeps the time it was originally fetched, so may
+    /// already be stale.
+    pub fn load<P: AsRef<Path>>(path: P, ttl: Duration) -> Result<LookupCache> {
+        let saved: SavedLookups = serde_json::from_reader(BufReader::new(File::open(path)?))?;
+        Ok(LookupCache {
+            ttl,
+            fetched_at: Some(UNIX_EPOCH + Duration::from_secs(saved.fetched_at)),
+            tables: saved.tables,
+        })
+    }
+
+    /// Save the cache to disk. Fails if the cache has never been fetched.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let fetched_at = self.fetched_at.ok_or("lookup cache has not been fetched")?;
+        let saved = SavedLookups {
+            fetched_at: fetched_at.duration_since(UNIX_EPOCH)?.as_secs(),
+            tables: self.tables.clone(),
+        };
+        serde_json::to_writer(BufWriter::new(File::create(path)?), &saved)?;
+        Ok(())
+    }
+
+    /// How long the Views are kept once fetched
+    pub fn ttl(&self) -> Duration {
+        self.ttl
+    }
+
+    /// Change how long the Views are kept once fetched
+    pub fn set_ttl(&mut self, ttl: Duration) {
+        self.ttl = ttl;
+    }
+
+    /// Has the cache never been fetched, or been kept longer than its TTL?
+    pub fn is_stale(&self) -> bool {
+        match self.fetched_at.map(|fetched_at| fetched_at.elapsed()) {
+            Some(Ok(age)) => age >= self.ttl,
+            // A fetch time in the future means the clock has changed, so fetch again
+            _ => true,
+        }
+    }
+
+    /// Replace the cached Views, marking them as fetched now
+    pub fn set_tables(&mut self, tables: LookupTables) {
+        self.tables = tables;
+        self.fetched_at = Some(SystemTime::now());
+    }
+
+    /// The rows of each cached View
+    pub fn tables(&self) -> &LookupTables {
+        &self.tables
+    }
+
+    /// Fetch every View from Buzz, regardless of whether the cache is stale
+    pub fn refresh_sync<C: SyncClient>(&mut self, beeswax_client: &C) -> Result<()> {
+        self.set_tables(LookupTables {
+            countries: beeswax_client.read_view(ViewName::Countries)?,
+            creative_sizes: beeswax_client.read_view(ViewName::CreativeSizes)?,
+            currencies: beeswax_client.read_view(ViewName::Currency)?,
+            mime_types: beeswax_client.read_view(ViewName::MimeTypes)?,
+        });
+        Ok(())
+    }
+
+    /// Fetch every View from Buzz if the cache is stale
+    pub fn refresh_if_stale_sync<C: SyncClient>(&mut self, beeswax_client: &C) -> Result<()> {
+        if self.is_stale() {
+            self.refresh_sync(beeswax_client)?;
+        }
+        Ok(())
+    }
+
+    /// Find a country by its alpha-3 or alpha-2 code, ignoring case
+    pub fn country_by_code(&self, code: &str) -> Option<&Country> {
+        self.tables.countries.iter().find(|country| {
+            country.country_code.eq_ignore_ascii_case(code)
+                || country
+                    .country_code_2
+                    .as_ref()
+                    .map(|country_code_2| country_code_2.eq_ignore_ascii_case(code))
+                    .unwrap_or(false)
+        })
+    }
+
+    /// Find a currency by its ISO 4217 code, ignoring case
+    pub fn currency_by_code(&self, code: &str) -> Option<&Currency> {
+        self.tables
+            .currencies
+            .iter()
+            .find(|currency| currency.currency_code.eq_ignore_ascii_case(code))
+    }
+
+    /// Is width x height one of the supported Creative sizes?
+    pub fn is_valid_creative_size(&self, width: u64, height: u64) -> bool {
+        self.tables
+            .creative_sizes
+            .iter()
+            .any(|size| size.width == width && size.height == height)
+    }
+
+    /// Is the mime type acceptable for Creative Asset upload?
+    pub fn is_valid_mime_type(&self, mime_type: &str) -> bool {
+        self.tables
+            .mime_types
+            .iter()
+            .any(|row| row.mime_type.eq_ignore_ascii_case(mime_type))
+    }
+}
+
+impl_async_clients! {
+    /// Fetch every View in the lookup cache, regardless of whether it is stale
+    pub async fn refresh_lookups(&self, lookup_cache: &mut LookupCache) -> Result<()> {
+        lookup_cache.set_tables(LookupTables {
+            countries: self.read_view(ViewName::Countries).await?,
+            creative_sizes: self.read_view(ViewName::CreativeSizes).await?,
+            currencies: self.read_view(ViewName::Currency).await?,
+            mime_types: self.read_view(ViewName::MimeTypes).await?,
+        });
+        Ok(())
+    }
+
+    /// Fetch every View in the lookup cache if it is stale
+    pub async fn refresh_lookups_if_stale(&self, lookup_cache: &mut LookupCache) -> Result<()> {
+        if lookup_cache.is_stale() {
+            self.refresh_lookups(lookup_cache).await?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+//! The terms shared by Bid Modifiers and Delivery Modifiers. A term matches an auction field
+//! against a value, and carries the adjustment its modifier makes to auctions that match.
+
+/// Define a modifier term type, named after the adjustment it makes when it matches
+macro_rules! modifier_term {
+    (
+        $(#[$term_meta:meta])*
+        $term:ident {
+            $(#[$adjustment_meta:meta])*
+            $adjustment:ident
+        }
+    ) => {
+        $(#[$term_meta])*
+        #[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+        pub struct $term {
+            /// The auction field to match on, e.g. "domain" or "geo.country"
+            pub field: String,
+            /// The value the field must match
+            pub value: TermValue,
+            $(#[$adjustment_meta])*
+            pub $adjustment: f64,
+        }
+
+        impl $term {
+            /// Create a term making the adjustment to auctions where field matches value
+            pub fn new<F: Into<String>, V: Into<TermValue>>(
+                field: F,
+                value: V,
+                $adjustment: f64,
+            ) -> $term {
+                $term {
+                    field: field.into(),
+                    value: value.into(),
+                    $adjustment,
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use modifier_term;
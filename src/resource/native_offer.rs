@@ -0,0 +1,390 @@
+//! A Native Offer holds the content of a Native Creative, such as its title, description, images
+//! and call to action. Exchanges assemble the ad from these parts to match the look of the page it
+//! appears on. A Native Creative uses an offer by setting native_offer to its native_offer_id.
+
+use crate::client::{async_client::impl_async_clients, sync_client::SyncClient};
+use crate::resource::{
+    common::CreativeType,
+    creative::{CreateCreative, Creative},
+    Create, Delete, Read, Resource,
+};
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+/// The role an image plays in a native ad
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum NativeImageType {
+    /// A small square image, such as a logo
+    Icon,
+    /// The large main image of the ad
+    Main,
+}
+
+/// An image of a Native Offer
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+pub struct NativeOfferImage {
+    /// The role the image plays in the ad
+    pub image_type: NativeImageType,
+    /// ID of the creative_asset holding the image
+    pub creative_asset_id: u64,
+    /// Width of the image in pixels
+    pub width: Option<u64>,
+    /// Height of the image in pixels
+    pub height: Option<u64>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct NativeOffer {
+    /// Unique ID of the Native Offer
+    pub native_offer_id: u64,
+
+    /// The Advertiser the Native Offer belongs to
+    pub advertiser_id: u64,
+
+    /// Name of the Native Offer, e.g. "Winter sale native"
+    pub native_offer_name: String,
+
+    /// Headline of the ad, e.g. "Half price winter coats"
+    pub title: String,
+
+    /// Body text of the ad
+    pub description: Option<String>,
+
+    /// The icon and main images of the ad
+    pub images: Vec<NativeOfferImage>,
+
+    /// Text of the button, e.g. "Shop now"
+    pub call_to_action: Option<String>,
+
+    /// Name of the brand shown as sponsoring the ad
+    pub sponsor: Option<String>,
+
+    /// An alternative id to lookup the Native Offer, if desired
+    pub alternative_id: Option<String>,
+
+    /// Notes about the Native Offer, up to 255 chars
+    pub notes: Option<String>,
+
+    /// Is the Native Offer active?
+    pub active: Option<bool>,
+
+    // Undocumented
+    pub account_id: Option<u64>,
+    pub create_date: Option<String>,
+    pub update_date: Option<String>,
+}
+
+impl NativeOffer {
+    /// Create a builder for CreateNativeOffer
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::NativeOffer;
+    ///
+    /// let create_native_offer = NativeOffer::create_builder()
+    ///     .advertiser_id(1)
+    ///     .native_offer_name("Winter sale native")
+    ///     .title("Half price winter coats")
+    ///     .sponsor("Example Outfitters".to_string())
+    ///     .build();
+    ///
+    /// let native_offer = beeswax_client.create(&create_native_offer).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn create_builder() -> CreateNativeOfferBuilder<((), (), (), (), (), (), (), (), (), ())> {
+        CreateNativeOffer::builder()
+    }
+
+    /// Create a builder for ReadNativeOffer
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::NativeOffer;
+    ///
+    /// let read_native_offer = NativeOffer::read_builder()
+    ///     .advertiser_id(1)
+    ///     .build();
+    ///
+    /// let native_offers = beeswax_client.read(&read_native_offer).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadNativeOfferBuilder<((), (), (), (), ())> {
+        ReadNativeOffer::builder()
+    }
+
+    /// Create a builder for DeleteNativeOffer
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::NativeOffer;
+    ///
+    /// let delete_native_offer = NativeOffer::delete_builder()
+    ///     .native_offer_id(10)
+    ///     .build();
+    ///
+    /// beeswax_client.delete(&delete_native_offer).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_builder() -> DeleteNativeOfferBuilder<((),)> {
+        DeleteNativeOffer::builder()
+    }
+
+    /// The first image of the given type, if any
+    pub fn image(&self, image_type: NativeImageType) -> Option<&NativeOfferImage> {
+        self.images
+            .iter()
+            .find(|image| image.image_type == image_type)
+    }
+}
+
+impl Resource for NativeOffer {
+    const NAME: &'static str = "native_offer";
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadNativeOffer {
+    /// Unique ID of the Native Offer
+    #[builder(default, setter(into))]
+    pub native_offer_id: Option<u64>,
+    /// The Advertiser the Native Offer belongs to
+    #[builder(default, setter(into))]
+    pub advertiser_id: Option<u64>,
+    /// Name of the Native Offer. Supports %LIKE% syntax
+    #[builder(default, setter(into))]
+    pub native_offer_name: Option<String>,
+    /// An alternative id to lookup the Native Offer, if desired
+    #[builder(default, setter(into))]
+    pub alternative_id: Option<String>,
+    /// Is the Native Offer active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Read<NativeOffer> for ReadNativeOffer {}
+
+impl PartialEq<NativeOffer> for ReadNativeOffer {
+    fn eq(&self, other: &NativeOffer) -> bool {
+        (self.native_offer_id.is_none() || self.native_offer_id == Some(other.native_offer_id))
+            && (self.advertiser_id.is_none() || self.advertiser_id == Some(other.advertiser_id))
+            && (self.native_offer_name.is_none()
+                || self.native_offer_name.as_ref() == Some(&other.native_offer_name))
+            && (self.alternative_id.is_none() || self.alternative_id == other.alternative_id)
+            && (self.active.is_none() || self.active == other.active)
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct CreateNativeOffer {
+    /// The Advertiser the Native Offer belongs to
+    pub advertiser_id: u64,
+
+    /// Name of the Native Offer, e.g. "Winter sale native"
+    #[builder(setter(into))]
+    pub native_offer_name: String,
+
+    /// Headline of the ad, e.g. "Half price winter coats"
+    #[builder(setter(into))]
+    pub title: String,
+
+    /// Body text of the ad
+    #[builder(default, setter(into))]
+    pub description: Option<String>,
+
+    /// The icon and main images of the ad
+    #[builder(default)]
+    pub images: Vec<NativeOfferImage>,
+
+    /// Text of the button, e.g. "Shop now"
+    #[builder(default, setter(into))]
+    pub call_to_action: Option<String>,
+
+    /// Name of the brand shown as sponsoring the ad
+    #[builder(default, setter(into))]
+    pub sponsor: Option<String>,
+
+    /// An alternative id to lookup the Native Offer, if desired
+    #[builder(default, setter(into))]
+    pub alternative_id: Option<String>,
+
+    /// Notes about the Native Offer, up to 255 chars
+    #[builder(default, setter(into))]
+    pub notes: Option<String>,
+
+    /// Is the Native Offer active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Create<NativeOffer> for CreateNativeOffer {
+    fn into_resource(self, native_offer_id: u64) -> NativeOffer {
+        NativeOffer {
+            native_offer_id,
+            advertiser_id: self.advertiser_id,
+            native_offer_name: self.native_offer_name,
+            title: self.title,
+            description: self.description,
+            images: self.images,
+            call_to_action: self.call_to_action,
+            sponsor: self.sponsor,
+            alternative_id: self.alternative_id,
+            notes: self.notes,
+            active: self.active,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TypedBuilder)]
+pub struct DeleteNativeOffer {
+    native_offer_id: u64,
+}
+
+impl Delete<NativeOffer> for DeleteNativeOffer {}
+
+impl Delete<NativeOffer> for NativeOffer {}
+
+/// Check the Creative can use an offer of the given Advertiser before anything is created
+fn check_advertiser(
+    create_native_offer: &CreateNativeOffer,
+    create_creative: &CreateCreative,
+) -> Result<()> {
+    if create_native_offer.advertiser_id != create_creative.advertiser_id {
+        return Err(format!(
+            "native offer belongs to advertiser {} but creative to advertiser {}",
+            create_native_offer.advertiser_id, create_creative.advertiser_id
+        )
+        .into());
+    }
+    Ok(())
+}
+
+/// Use the Native Offer as the content of a Native Creative
+fn native_creative(native_offer: &NativeOffer, create_creative: &CreateCreative) -> CreateCreative {
+    let mut create_creative = create_creative.clone();
+    create_creative.creative_type = CreativeType::Native;
+    create_creative.native_offer = Some(native_offer.native_offer_id);
+    create_creative
+}
+
+/// The error creating the Creative, plus the error deleting the offer created for it if that failed
+/// too
+fn native_creative_error(
+    error: Box<dyn std::error::Error>,
+    delete_result: Result<()>,
+) -> Box<dyn std::error::Error> {
+    match delete_result {
+        Ok(()) => error,
+        Err(delete_error) => format!(
+            "{}, deleting the native offer also failed: {}",
+            error, delete_error
+        )
+        .into(),
+    }
+}
+
+impl CreateNativeOffer {
+    /// Create the Native Offer and then a Native Creative using it. Both must be for the same
+    /// Advertiser. If the Creative can't be created, the offer is deleted again.
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::sync_client::SyncInMemoryClient;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # let beeswax_client = SyncInMemoryClient::new();
+    /// use beeswax::resource::{common::CreativeType, Creative, NativeOffer};
+    ///
+    /// let create_native_offer = NativeOffer::create_builder()
+    ///     .advertiser_id(1)
+    ///     .native_offer_name("Winter sale native")
+    ///     .title("Half price winter coats")
+    ///     .build();
+    ///
+    /// let create_creative = Creative::create_builder()
+    ///     .advertiser_id(1)
+    ///     .creative_name("Winter sale native")
+    ///     .build();
+    ///
+    /// let creative =
+    ///     create_native_offer.create_native_creative_sync(&beeswax_client, &create_creative)?;
+    ///
+    /// assert_eq!(creative.creative_type, CreativeType::Native);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_native_creative_sync<C: SyncClient>(
+        &self,
+        beeswax_client: &C,
+        create_creative: &CreateCreative,
+    ) -> Result<Creative> {
+        check_advertiser(self, create_creative)?;
+        let native_offer = self.create_sync(beeswax_client)?;
+        native_creative(&native_offer, create_creative)
+            .create_sync(beeswax_client)
+            .map_err(|error| native_creative_error(error, native_offer.delete_sync(beeswax_client)))
+    }
+}
+
+impl_async_clients! {
+    /// Create the Native Offer and then a Native Creative using it. Both must be for the same
+    /// Advertiser. If the Creative can't be created, the offer is deleted again.
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::{common::CreativeType, Creative, NativeOffer};
+    ///
+    /// let create_native_offer = NativeOffer::create_builder()
+    ///     .advertiser_id(1)
+    ///     .native_offer_name("Winter sale native")
+    ///     .title("Half price winter coats")
+    ///     .build();
+    ///
+    /// let create_creative = Creative::create_builder()
+    ///     .advertiser_id(1)
+    ///     .creative_name("Winter sale native")
+    ///     .build();
+    ///
+    /// let creative = beeswax_client
+    ///     .create_native_creative(&create_native_offer, &create_creative)
+    ///     .await?;
+    ///
+    /// assert_eq!(creative.creative_type, CreativeType::Native);
+    /// assert!(creative.native_offer.is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_native_creative(
+        &self,
+        create_native_offer: &CreateNativeOffer,
+        create_creative: &CreateCreative,
+    ) -> Result<Creative> {
+        check_advertiser(create_native_offer, create_creative)?;
+        let native_offer = self.create(create_native_offer).await?;
+        match self
+            .create(&native_creative(&native_offer, create_creative))
+            .await
+        {
+            Ok(creative) => Ok(creative),
+            Err(error) => Err(native_creative_error(
+                error,
+                self.delete(&native_offer).await,
+            )),
+        }
+    }
+}
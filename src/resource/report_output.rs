@@ -0,0 +1,427 @@
+//! Streaming of completed reports. Report files can run to millions of rows, so rather than
+//! buffering the whole file, rows are decoded as the file downloads and either re-encoded straight
+//! into a writer as CSV, TSV or JSON lines, or deserialized one at a time into the caller's type.
+//! The columns of a report are described by the report_fields view, surfaced here as ReportField.
+
+use crate::client::{async_client::impl_async_clients, sync_client::SyncClient};
+use crate::resource::{
+    common::ViewName,
+    report_queue::{ReportFileFormat, ReportQueue},
+};
+use crate::Result;
+use csv::StringRecord;
+use csv_core::ReadRecordResult;
+use futures_util::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{Map, Value as JsonValue};
+use std::io::{Read, Write};
+use std::marker::PhantomData;
+
+/// The form to write a report in when streaming it
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ReportOutput {
+    /// Comma separated values, with a header row
+    Csv,
+    /// Tab separated values, with a header row
+    Tsv,
+    /// One JSON object per line, keyed by column name. Values are written as strings, and empty
+    /// cells as null.
+    JsonLines,
+}
+
+/// A column that can be included in a report, as found in the report_fields view
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct ReportField {
+    /// The report the field belongs to, e.g. "performance_agg"
+    pub view_name: String,
+    /// Name of the field, as used in the fields of a Report Queue
+    pub field_name: String,
+    /// Name of the field to show in a UI, e.g. "Impressions"
+    pub display_name: Option<String>,
+    /// Type of data held in the column, e.g. "integer"
+    pub data_type: Option<String>,
+    /// Description of the field
+    pub description: Option<String>,
+}
+
+/// Keep the fields belonging to the given report from the rows of the report_fields view
+fn fields_of_report(report_fields: Vec<ReportField>, view_name: &str) -> Vec<ReportField> {
+    report_fields
+        .into_iter()
+        .filter(|report_field| report_field.view_name == view_name)
+        .collect()
+}
+
+/// Pick out the fields of a Report Queue, in the order they appear as columns
+fn report_columns(report_fields: Vec<ReportField>, report_queue: &ReportQueue) -> Vec<ReportField> {
+    report_queue
+        .fields
+        .iter()
+        .filter_map(|field_name| {
+            report_fields
+                .iter()
+                .find(|report_field| &report_field.field_name == field_name)
+                .cloned()
+        })
+        .collect()
+}
+
+impl ReportField {
+    /// Find the fields available to the given report
+    pub fn read_sync<C: SyncClient>(
+        beeswax_client: &C,
+        view_name: &str,
+    ) -> Result<Vec<ReportField>> {
+        let report_fields = beeswax_client.read_view(ViewName::ReportFields)?;
+        Ok(fields_of_report(report_fields, view_name))
+    }
+}
+
+/// Re-encodes the records of a report into the requested output, one record at a time
+struct RecordEncoder {
+    output: ReportOutput,
+    headers: StringRecord,
+    csv: csv::WriterBuilder,
+    buffer: Vec<u8>,
+}
+
+impl RecordEncoder {
+    fn new(output: ReportOutput, headers: StringRecord) -> RecordEncoder {
+        let delimiter = match output {
+            ReportOutput::Tsv => b'\t',
+            _ => b',',
+        };
+        let mut csv = csv::WriterBuilder::new();
+        csv.delimiter(delimiter);
+        RecordEncoder {
+            output,
+            headers,
+            csv,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// The bytes to write before the first record
+    fn start(&mut self) -> Result<&[u8]> {
+        match self.output {
+            ReportOutput::JsonLines => Ok(&[]),
+            _ => {
+                let headers = self.headers.clone();
+                self.encode(&headers)
+            }
+        }
+    }
+
+    /// The bytes of a single encoded record, including its line ending
+    fn encode(&mut self, record: &StringRecord) -> Result<&[u8]> {
+        self.buffer.clear();
+        match self.output {
+            ReportOutput::JsonLines => {
+                let row: Map<String, JsonValue> = self
+                    .headers
+                    .iter()
+                    .zip(record.iter())
+                    .map(|(header, field)| {
+                        let value = match field {
+                            "" => JsonValue::Null,
+                            field => JsonValue::String(field.to_string()),
+                        };
+                        (header.to_string(), value)
+                    })
+                    .collect();
+                serde_json::to_writer(&mut self.buffer, &row)?;
+                self.buffer.push(b'\n');
+            }
+            _ => {
+                let mut writer = self.csv.from_writer(&mut self.buffer);
+                writer.write_record(record)?;
+                writer.flush()?;
+            }
+        }
+        Ok(&self.buffer)
+    }
+}
+
+/// Decodes records from a report file as it is read, without holding more than the current record
+struct RecordDecoder<R> {
+    source: R,
+    csv: csv_core::Reader,
+    input: Vec<u8>,
+    input_start: usize,
+    input_end: usize,
+    output: Vec<u8>,
+    ends: Vec<usize>,
+}
+
+impl<R: AsyncRead + Unpin> RecordDecoder<R> {
+    fn new(source: R, file_format: ReportFileFormat) -> RecordDecoder<R> {
+        RecordDecoder {
+            source,
+            csv: csv_core::ReaderBuilder::new()
+                .delimiter(file_format.delimiter())
+                .build(),
+            input: vec![0; 8 * 1024],
+            input_start: 0,
+            input_end: 0,
+            output: vec![0; 1024],
+            ends: vec![0; 32],
+        }
+    }
+
+    /// Read the next record, or None once the file is finished
+    async fn next_record(&mut self) -> Result<Option<StringRecord>> {
+        let (mut output_len, mut ends_len) = (0, 0);
+        loop {
+            if self.input_start == self.input_end {
+                // An empty read marks the end of the file for the csv reader
+                self.input_end = self.source.read(&mut self.input).await?;
+                self.input_start = 0;
+            }
+            let (result, read, written, ended) = self.csv.read_record(
+                &self.input[self.input_start..self.input_end],
+                &mut self.output[output_len..],
+                &mut self.ends[ends_len..],
+            );
+            self.input_start += read;
+            output_len += written;
+            ends_len += ended;
+            match result {
+                ReadRecordResult::InputEmpty => {}
+                ReadRecordResult::OutputFull => self.output.resize(self.output.len() * 2, 0),
+                ReadRecordResult::OutputEndsFull => self.ends.resize(self.ends.len() * 2, 0),
+                ReadRecordResult::Record => return self.record(output_len, ends_len).map(Some),
+                ReadRecordResult::End => return Ok(None),
+            }
+        }
+    }
+
+    fn record(&self, output_len: usize, ends_len: usize) -> Result<StringRecord> {
+        let output = &self.output[..output_len];
+        let mut fields = Vec::with_capacity(ends_len);
+        let mut start = 0;
+        for &end in &self.ends[..ends_len] {
+            fields.push(std::str::from_utf8(&output[start..end])?);
+            start = end;
+        }
+        Ok(StringRecord::from(fields))
+    }
+}
+
+/// The rows of a report deserialized one at a time as the report downloads
+pub struct ReportRows<T> {
+    rows: csv::DeserializeRecordsIntoIter<Box<dyn Read + Send>, T>,
+}
+
+impl<T: DeserializeOwned> Iterator for ReportRows<T> {
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.rows.next().map(|row| row.map_err(Into::into))
+    }
+}
+
+/// The rows of a report deserialized one at a time as the report downloads, for use with the
+/// async clients
+pub struct AsyncReportRows<T> {
+    decoder: RecordDecoder<Box<dyn AsyncRead + Unpin + Send>>,
+    headers: StringRecord,
+    row: PhantomData<T>,
+}
+
+impl<T: DeserializeOwned> AsyncReportRows<T> {
+    async fn new(
+        source: Box<dyn AsyncRead + Unpin + Send>,
+        file_format: ReportFileFormat,
+    ) -> Result<AsyncReportRows<T>> {
+        let mut decoder = RecordDecoder::new(source, file_format);
+        let headers = decoder.next_record().await?.unwrap_or_default();
+        Ok(AsyncReportRows {
+            decoder,
+            headers,
+            row: PhantomData,
+        })
+    }
+
+    /// Names of the report's columns
+    pub fn headers(&self) -> &StringRecord {
+        &self.headers
+    }
+
+    /// Deserialize the next row, or None once the report is finished
+    pub async fn next_row(&mut self) -> Result<Option<T>> {
+        match self.decoder.next_record().await? {
+            Some(record) => Ok(Some(record.deserialize(Some(&self.headers))?)),
+            None => Ok(None),
+        }
+    }
+}
+
+impl ReportFileFormat {
+    fn csv_reader<R: Read>(self, source: R) -> csv::Reader<R> {
+        csv::ReaderBuilder::new()
+            .delimiter(self.delimiter())
+            .from_reader(source)
+    }
+
+    /// Re-encode a report file read from source into the writer in the given output, returning
+    /// the number of rows written
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use beeswax::resource::{report_output::ReportOutput, report_queue::ReportFileFormat};
+    ///
+    /// let mut json_lines = Vec::new();
+    /// let report = "campaign_id,impressions\n1,100\n".as_bytes();
+    ///
+    /// ReportFileFormat::Csv.stream(report, ReportOutput::JsonLines, &mut json_lines)?;
+    ///
+    /// assert_eq!(json_lines, b"{\"campaign_id\":\"1\",\"impressions\":\"100\"}\n");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream<R: Read, W: Write>(
+        self,
+        source: R,
+        output: ReportOutput,
+        writer: &mut W,
+    ) -> Result<u64> {
+        let mut reader = self.csv_reader(source);
+        let mut encoder = RecordEncoder::new(output, reader.headers()?.clone());
+        writer.write_all(encoder.start()?)?;
+        let mut rows = 0;
+        for record in reader.records() {
+            writer.write_all(encoder.encode(&record?)?)?;
+            rows += 1;
+        }
+        writer.flush()?;
+        Ok(rows)
+    }
+
+    /// Re-encode a report file read asynchronously from source into the writer in the given
+    /// output, returning the number of rows written
+    pub async fn stream_async<R: AsyncRead + Unpin, W: AsyncWrite + Unpin>(
+        self,
+        source: R,
+        output: ReportOutput,
+        writer: &mut W,
+    ) -> Result<u64> {
+        let mut decoder = RecordDecoder::new(source, self);
+        let headers = decoder.next_record().await?.unwrap_or_default();
+        let mut encoder = RecordEncoder::new(output, headers);
+        writer.write_all(encoder.start()?).await?;
+        let mut rows = 0;
+        while let Some(record) = decoder.next_record().await? {
+            writer.write_all(encoder.encode(&record)?).await?;
+            rows += 1;
+        }
+        writer.flush().await?;
+        Ok(rows)
+    }
+}
+
+impl ReportQueue {
+    /// Stream the completed report into the writer in the given output, returning the number of
+    /// rows written
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::sync_client::SyncInMemoryClient;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # let beeswax_client = SyncInMemoryClient::new();
+    /// use beeswax::resource::{report_output::ReportOutput, ReportQueue};
+    /// use std::io::stdout;
+    ///
+    /// let report_queue = ReportQueue::default();
+    ///
+    /// let rows = report_queue.stream_sync(&beeswax_client, ReportOutput::JsonLines, &mut stdout())?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn stream_sync<C: SyncClient, W: Write>(
+        &self,
+        beeswax_client: &C,
+        output: ReportOutput,
+        writer: &mut W,
+    ) -> Result<u64> {
+        let source = beeswax_client.download_reader::<ReportQueue>(self.report_queue_id)?;
+        self.file_format.stream(source, output, writer)
+    }
+
+    /// Iterate over the rows of the completed report, deserializing each into the given type as
+    /// the report downloads
+    pub fn rows_iter_sync<C: SyncClient, T: DeserializeOwned>(
+        &self,
+        beeswax_client: &C,
+    ) -> Result<ReportRows<T>> {
+        let source = beeswax_client.download_reader::<ReportQueue>(self.report_queue_id)?;
+        Ok(ReportRows {
+            rows: self.file_format.csv_reader(source).into_deserialize(),
+        })
+    }
+
+    /// Find the metadata of each of the report's columns, in the order they appear. Fields
+    /// missing from the report_fields view are skipped.
+    pub fn columns_sync<C: SyncClient>(&self, beeswax_client: &C) -> Result<Vec<ReportField>> {
+        let report_fields = ReportField::read_sync(beeswax_client, &self.view_name)?;
+        Ok(report_columns(report_fields, self))
+    }
+}
+
+impl_async_clients! {
+    /// Stream the completed report into the writer in the given output, returning the number of
+    /// rows written
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::{report_output::ReportOutput, ReportQueue};
+    ///
+    /// let report_queue = ReportQueue::default();
+    /// let mut report = Vec::new();
+    ///
+    /// let rows = beeswax_client
+    ///     .stream_report(&report_queue, ReportOutput::Tsv, &mut report)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stream_report<W: AsyncWrite + Unpin>(
+        &self,
+        report_queue: &ReportQueue,
+        output: ReportOutput,
+        writer: &mut W,
+    ) -> Result<u64> {
+        let source = self
+            .download_reader::<ReportQueue>(report_queue.report_queue_id)
+            .await?;
+        report_queue
+            .file_format
+            .stream_async(source, output, writer)
+            .await
+    }
+
+    /// Read the rows of the completed report one at a time, deserializing each into the given
+    /// type as the report downloads
+    pub async fn report_row_reader<T: DeserializeOwned>(
+        &self,
+        report_queue: &ReportQueue,
+    ) -> Result<AsyncReportRows<T>> {
+        let source = self
+            .download_reader::<ReportQueue>(report_queue.report_queue_id)
+            .await?;
+        AsyncReportRows::new(source, report_queue.file_format).await
+    }
+
+    /// Find the fields available to the given report
+    pub async fn report_fields(&self, view_name: &str) -> Result<Vec<ReportField>> {
+        let report_fields = self.read_view(ViewName::ReportFields).await?;
+        Ok(fields_of_report(report_fields, view_name))
+    }
+
+    /// Find the metadata of each of the report's columns, in the order they appear
+    pub async fn report_columns(&self, report_queue: &ReportQueue) -> Result<Vec<ReportField>> {
+        let report_fields = self.report_fields(&report_queue.view_name).await?;
+        Ok(report_columns(report_fields, report_queue))
+    }
+}
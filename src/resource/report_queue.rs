@@ -0,0 +1,448 @@
+//! The Report Queue runs reports asynchronously. A report is submitted to the queue, Buzz runs it
+//! in the background and the result can be downloaded as a file once it is complete. The reports
+//! available, and the fields and date filters each supports, can be found in the reports,
+//! report_fields and report_date_filters views.
+
+use crate::client::{async_client::impl_async_clients, sync_client::SyncClient, PollOptions};
+use crate::resource::{AnyResource, Create, Delete, Read, Resource};
+use crate::Result;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::Write;
+use typed_builder::TypedBuilder;
+
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportStatus {
+    /// The report is waiting to be run
+    Queued,
+    /// The report is being run
+    Running,
+    /// The report has finished and can be downloaded
+    Complete,
+    /// The report could not be run
+    Failed,
+}
+
+#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportFileFormat {
+    /// Comma separated values
+    #[default]
+    Csv,
+    /// Tab separated values
+    Tsv,
+}
+
+impl ReportFileFormat {
+    pub(crate) fn delimiter(self) -> u8 {
+        match self {
+            ReportFileFormat::Csv => b',',
+            ReportFileFormat::Tsv => b'\t',
+        }
+    }
+
+    /// Deserialize the rows of a downloaded report file into the given type. Each column of the
+    /// report is matched to the field of the same name.
+    /// ```
+    /// # use std::error::Error;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// use beeswax::resource::report_queue::ReportFileFormat;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Row {
+    ///     campaign_id: u64,
+    ///     impressions: u64,
+    /// }
+    ///
+    /// let rows: Vec<Row> =
+    ///     ReportFileFormat::Csv.parse_rows(b"campaign_id,impressions\n1,100\n2,200\n")?;
+    ///
+    /// assert_eq!(rows[1].impressions, 200);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn parse_rows<T: DeserializeOwned>(self, report: &[u8]) -> Result<Vec<T>> {
+        let rows = csv::ReaderBuilder::new()
+            .delimiter(self.delimiter())
+            .from_reader(report)
+            .deserialize()
+            .collect::<std::result::Result<_, _>>()?;
+        Ok(rows)
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct ReportQueue {
+    /// Unique ID of the queued report
+    pub report_queue_id: u64,
+
+    /// The report to run, as found in the reports view, e.g. "performance_agg"
+    pub view_name: String,
+
+    /// The fields to include in the report, as found in the report_fields view
+    pub fields: Vec<String>,
+
+    /// Filters to apply to the report keyed by field, e.g. "campaign_id": "=1"
+    pub filters: Option<HashMap<String, String>>,
+
+    /// Include only rows on or after this date
+    pub start_date: Option<String>,
+
+    /// Include only rows on or before this date
+    pub end_date: Option<String>,
+
+    /// A special date range from the report_date_filters view, e.g. "yesterday"
+    pub date_filter: Option<String>,
+
+    /// Fields to sort the report by
+    pub sort_by: Option<Vec<String>>,
+
+    /// Maximum number of rows to return
+    pub rows: Option<u64>,
+
+    /// Format of the report file
+    pub file_format: ReportFileFormat,
+
+    /// Where the report is up to
+    pub report_status: Option<ReportStatus>,
+
+    // Undocumented
+    pub error_message: Option<String>,
+    pub row_count: Option<u64>,
+    pub account_id: Option<u64>,
+    pub create_date: Option<String>,
+    pub update_date: Option<String>,
+}
+
+impl ReportQueue {
+    /// Create a builder for CreateReportQueue
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::ReportQueue;
+    ///
+    /// let create_report_queue = ReportQueue::create_builder()
+    ///     .view_name("performance_agg")
+    ///     .fields(vec!["campaign_id".to_string(), "impressions".to_string()])
+    ///     .start_date("2020-01-01".to_string())
+    ///     .end_date("2020-01-31".to_string())
+    ///     .build();
+    ///
+    /// let report_queue = beeswax_client.create(&create_report_queue).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn create_builder() -> CreateReportQueueBuilder<((), (), (), (), (), (), (), (), ())> {
+        CreateReportQueue::builder()
+    }
+
+    /// Create a builder for ReadReportQueue
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::ReportQueue;
+    ///
+    /// let read_report_queue = ReportQueue::read_builder()
+    ///     .report_queue_id(1)
+    ///     .build();
+    ///
+    /// let report_queues = beeswax_client.read(&read_report_queue).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadReportQueueBuilder<((), (), ())> {
+        ReadReportQueue::builder()
+    }
+
+    /// Create a builder for DeleteReportQueue
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::ReportQueue;
+    ///
+    /// let delete_report_queue = ReportQueue::delete_builder()
+    ///     .report_queue_id(10)
+    ///     .build();
+    ///
+    /// beeswax_client.delete(&delete_report_queue).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_builder() -> DeleteReportQueueBuilder<((),)> {
+        DeleteReportQueue::builder()
+    }
+
+    /// Check whether the report has finished running. Reports with no status yet are treated as
+    /// still pending. Returns an error if the report failed.
+    pub fn is_complete(&self) -> Result<bool> {
+        match self.report_status {
+            Some(ReportStatus::Complete) => Ok(true),
+            None | Some(ReportStatus::Queued) | Some(ReportStatus::Running) => Ok(false),
+            Some(ReportStatus::Failed) => Err(format!(
+                "report {} failed: {}",
+                self.report_queue_id,
+                self.error_message.as_deref().unwrap_or("unknown error")
+            )
+            .into()),
+        }
+    }
+
+    /// Poll Buzz until this report has finished running, returning the completed report
+    pub fn wait_sync<C: SyncClient>(
+        &self,
+        beeswax_client: &C,
+        poll: PollOptions,
+    ) -> Result<ReportQueue> {
+        let read_report_queue = ReadReportQueue::from(self);
+        let report_queue = poll.poll_sync(|| {
+            let report_queue = read_report_queue
+                .read_sync(beeswax_client)?
+                .pop()
+                .ok_or("report not found")?;
+            completed(report_queue)
+        })?;
+        report_queue.ok_or_else(|| report_timeout(self.report_queue_id, poll))
+    }
+
+    /// Download the completed report file into the writer, returning the number of bytes written
+    pub fn download_sync<C: SyncClient, W: Write>(
+        &self,
+        beeswax_client: &C,
+        writer: &mut W,
+    ) -> Result<u64> {
+        beeswax_client.download::<ReportQueue, W>(self.report_queue_id, writer)
+    }
+
+    /// Download the completed report and deserialize each row into the given type
+    pub fn rows_sync<C: SyncClient, T: DeserializeOwned>(
+        &self,
+        beeswax_client: &C,
+    ) -> Result<Vec<T>> {
+        self.rows_iter_sync(beeswax_client)?.collect()
+    }
+}
+
+impl Resource for ReportQueue {
+    const NAME: &'static str = "report_queue";
+}
+
+fn completed(report_queue: ReportQueue) -> Result<Option<ReportQueue>> {
+    if report_queue.is_complete()? {
+        Ok(Some(report_queue))
+    } else {
+        Ok(None)
+    }
+}
+
+fn report_timeout(report_queue_id: u64, poll: PollOptions) -> Box<dyn std::error::Error> {
+    format!(
+        "report {} was not complete after {} attempts",
+        report_queue_id, poll.max_attempts
+    )
+    .into()
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadReportQueue {
+    /// Unique ID of the queued report
+    #[builder(default, setter(into))]
+    pub report_queue_id: Option<u64>,
+    /// The report that was run, e.g. "performance_agg"
+    #[builder(default, setter(into))]
+    pub view_name: Option<String>,
+    /// Where the report is up to
+    #[builder(default, setter(into))]
+    pub report_status: Option<ReportStatus>,
+}
+
+impl Read<ReportQueue> for ReadReportQueue {}
+
+impl PartialEq<ReportQueue> for ReadReportQueue {
+    fn eq(&self, other: &ReportQueue) -> bool {
+        (self.report_queue_id.is_none() || self.report_queue_id == Some(other.report_queue_id))
+            && (self.view_name.is_none() || self.view_name.as_ref() == Some(&other.view_name))
+            && (self.report_status.is_none() || self.report_status == other.report_status)
+    }
+}
+
+/// Create a search criteria for a specific queued report
+impl From<&ReportQueue> for ReadReportQueue {
+    fn from(report_queue: &ReportQueue) -> Self {
+        ReadReportQueue {
+            report_queue_id: Some(report_queue.report_queue_id),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct CreateReportQueue {
+    /// The report to run, as found in the reports view, e.g. "performance_agg"
+    #[builder(setter(into))]
+    pub view_name: String,
+
+    /// The fields to include in the report, as found in the report_fields view
+    pub fields: Vec<String>,
+
+    /// Filters to apply to the report keyed by field, e.g. "campaign_id": "=1"
+    #[builder(default, setter(into))]
+    pub filters: Option<HashMap<String, String>>,
+
+    /// Include only rows on or after this date
+    #[builder(default, setter(into))]
+    pub start_date: Option<String>,
+
+    /// Include only rows on or before this date
+    #[builder(default, setter(into))]
+    pub end_date: Option<String>,
+
+    /// A special date range from the report_date_filters view, e.g. "yesterday"
+    #[builder(default, setter(into))]
+    pub date_filter: Option<String>,
+
+    /// Fields to sort the report by
+    #[builder(default, setter(into))]
+    pub sort_by: Option<Vec<String>>,
+
+    /// Maximum number of rows to return
+    #[builder(default, setter(into))]
+    pub rows: Option<u64>,
+
+    /// Format of the report file
+    #[builder(default)]
+    pub file_format: ReportFileFormat,
+}
+
+impl Create<ReportQueue> for CreateReportQueue {
+    fn into_resource(self, report_queue_id: u64) -> ReportQueue {
+        ReportQueue {
+            report_queue_id,
+            view_name: self.view_name,
+            fields: self.fields,
+            filters: self.filters,
+            start_date: self.start_date,
+            end_date: self.end_date,
+            date_filter: self.date_filter,
+            sort_by: self.sort_by,
+            rows: self.rows,
+            file_format: self.file_format,
+            report_status: Some(ReportStatus::Queued),
+            ..Default::default()
+        }
+    }
+
+    fn create_into(&self, report_queue: &mut ReportQueue, _store: &mut [AnyResource]) {
+        // There is nothing to run in memory so the report is complete straight away
+        report_queue.report_status = Some(ReportStatus::Complete);
+    }
+}
+
+impl CreateReportQueue {
+    /// Submit the report, wait for it to complete, then download it and deserialize each row into
+    /// the given type
+    pub fn run_sync<C: SyncClient, T: DeserializeOwned>(
+        &self,
+        beeswax_client: &C,
+        poll: PollOptions,
+    ) -> Result<Vec<T>> {
+        self.create_sync(beeswax_client)?
+            .wait_sync(beeswax_client, poll)?
+            .rows_sync(beeswax_client)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TypedBuilder)]
+pub struct DeleteReportQueue {
+    report_queue_id: u64,
+}
+
+impl Delete<ReportQueue> for DeleteReportQueue {}
+
+impl Delete<ReportQueue> for ReportQueue {}
+
+impl_async_clients! {
+    /// Poll Buzz until the given report has finished running, returning the completed report
+    pub async fn wait_for_report(
+        &self,
+        report_queue: &ReportQueue,
+        poll: PollOptions,
+    ) -> Result<ReportQueue> {
+        let read_report_queue = &ReadReportQueue::from(report_queue);
+        let completed_report = poll
+            .poll_async(|| async move {
+                let report_queue = self
+                    .read(read_report_queue)
+                    .await?
+                    .pop()
+                    .ok_or("report not found")?;
+                completed(report_queue)
+            })
+            .await?;
+        completed_report.ok_or_else(|| report_timeout(report_queue.report_queue_id, poll))
+    }
+
+    /// Download the completed report and deserialize each row into the given type
+    pub async fn report_rows<T: DeserializeOwned>(
+        &self,
+        report_queue: &ReportQueue,
+    ) -> Result<Vec<T>> {
+        let mut row_reader = self.report_row_reader(report_queue).await?;
+        let mut rows = Vec::new();
+        while let Some(row) = row_reader.next_row().await? {
+            rows.push(row);
+        }
+        Ok(rows)
+    }
+
+    /// Submit the report, wait for it to complete, then download it and deserialize each row into
+    /// the given type
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::client::PollOptions;
+    /// use beeswax::resource::ReportQueue;
+    /// use serde::Deserialize;
+    ///
+    /// #[derive(Deserialize)]
+    /// struct Row {
+    ///     campaign_id: u64,
+    ///     impressions: u64,
+    /// }
+    ///
+    /// let create_report_queue = ReportQueue::create_builder()
+    ///     .view_name("performance_agg")
+    ///     .fields(vec!["campaign_id".to_string(), "impressions".to_string()])
+    ///     .date_filter("yesterday".to_string())
+    ///     .build();
+    ///
+    /// let rows: Vec<Row> = beeswax_client
+    ///     .run_report(&create_report_queue, PollOptions::default())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn run_report<T: DeserializeOwned>(
+        &self,
+        create_report_queue: &CreateReportQueue,
+        poll: PollOptions,
+    ) -> Result<Vec<T>> {
+        let report_queue = self.create(create_report_queue).await?;
+        let report_queue = self.wait_for_report(&report_queue, poll).await?;
+        self.report_rows(&report_queue).await
+    }
+}
@@ -0,0 +1,247 @@
+//! A Role is a named set of permissions granted to Users. Each permission sets the access a User
+//! has to one type of object, as found in the object_types view. Roles can be created, updated and
+//! deleted, though a Role cannot be deleted while Users still have it.
+
+use crate::resource::{Create, Delete, Read, Resource};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use typed_builder::TypedBuilder;
+
+/// Types of object access can be granted to, as found in the object_types view
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ObjectType {
+    Account,
+    Advertiser,
+    Campaign,
+    LineItem,
+    Creative,
+    CreativeAsset,
+    Segment,
+    Event,
+    Deal,
+    Report,
+    User,
+    Role,
+    TargetingTemplate,
+    /// An object type not known to this crate, by name
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// Level of access to a type of object. Each level includes the ones before it.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum Access {
+    /// No access
+    None,
+    /// May read objects
+    Read,
+    /// May read, create and update objects
+    Write,
+    /// May read, create, update and delete objects
+    Delete,
+}
+
+/// The access granted to each type of object. Object types missing from the set have no access.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct PermissionSet(BTreeMap<ObjectType, Access>);
+
+impl PermissionSet {
+    /// Create an empty set, granting no access
+    /// ```
+    /// use beeswax::resource::role::{Access, ObjectType, PermissionSet};
+    ///
+    /// let permissions = PermissionSet::new()
+    ///     .grant(ObjectType::Campaign, Access::Write)
+    ///     .grant(ObjectType::Report, Access::Read);
+    ///
+    /// assert!(permissions.allows(&ObjectType::Campaign, Access::Read));
+    /// assert!(!permissions.allows(&ObjectType::Campaign, Access::Delete));
+    /// assert!(!permissions.allows(&ObjectType::User, Access::Read));
+    /// ```
+    pub fn new() -> PermissionSet {
+        PermissionSet::default()
+    }
+
+    /// Grant the given access to a type of object, replacing any existing access
+    pub fn grant(mut self, object_type: ObjectType, access: Access) -> PermissionSet {
+        self.0.insert(object_type, access);
+        self
+    }
+
+    /// The access granted to a type of object
+    pub fn access(&self, object_type: &ObjectType) -> Access {
+        self.0.get(object_type).copied().unwrap_or(Access::None)
+    }
+
+    /// Is at least the given access granted to a type of object?
+    pub fn allows(&self, object_type: &ObjectType, access: Access) -> bool {
+        self.access(object_type) >= access
+    }
+
+    /// Iterate over the object types with access granted
+    pub fn iter(&self) -> impl Iterator<Item = (&ObjectType, &Access)> {
+        self.0.iter()
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct Role {
+    /// Unique ID of the Role
+    pub role_id: u64,
+
+    /// Name of the Role, e.g. "Trader"
+    pub role_name: String,
+
+    /// The access granted to Users with the Role
+    pub permissions: PermissionSet,
+
+    /// Notes about the Role, up to 255 chars
+    pub notes: Option<String>,
+
+    /// Is the Role active?
+    pub active: Option<bool>,
+
+    // Undocumented
+    pub account_id: Option<u64>,
+    pub create_date: Option<String>,
+    pub update_date: Option<String>,
+}
+
+impl Role {
+    /// Create a builder for CreateRole
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::{
+    ///     role::{Access, ObjectType, PermissionSet},
+    ///     Role,
+    /// };
+    ///
+    /// let create_role = Role::create_builder()
+    ///     .role_name("Trader")
+    ///     .permissions(PermissionSet::new().grant(ObjectType::LineItem, Access::Write))
+    ///     .build();
+    ///
+    /// let role = beeswax_client.create(&create_role).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_builder() -> CreateRoleBuilder<((), (), (), ())> {
+        CreateRole::builder()
+    }
+
+    /// Create a builder for ReadRole
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::Role;
+    ///
+    /// let read_role = Role::read_builder()
+    ///     .role_name("Trader".to_string())
+    ///     .build();
+    ///
+    /// let roles = beeswax_client.read(&read_role).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadRoleBuilder<((), (), ())> {
+        ReadRole::builder()
+    }
+
+    /// Create a builder for DeleteRole
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::Role;
+    ///
+    /// let delete_role = Role::delete_builder()
+    ///     .role_id(10)
+    ///     .build();
+    ///
+    /// beeswax_client.delete(&delete_role).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_builder() -> DeleteRoleBuilder<((),)> {
+        DeleteRole::builder()
+    }
+}
+
+impl Resource for Role {
+    const NAME: &'static str = "role";
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadRole {
+    /// Unique ID of the Role
+    #[builder(default, setter(into))]
+    pub role_id: Option<u64>,
+    /// Name of the Role. Supports %LIKE% syntax
+    #[builder(default, setter(into))]
+    pub role_name: Option<String>,
+    /// Is the Role active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Read<Role> for ReadRole {}
+
+impl PartialEq<Role> for ReadRole {
+    fn eq(&self, other: &Role) -> bool {
+        (self.role_id.is_none() || self.role_id == Some(other.role_id))
+            && (self.role_name.is_none() || self.role_name.as_ref() == Some(&other.role_name))
+            && (self.active.is_none() || self.active == other.active)
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct CreateRole {
+    /// Name of the Role, e.g. "Trader"
+    #[builder(setter(into))]
+    pub role_name: String,
+
+    /// The access granted to Users with the Role
+    #[builder(default)]
+    pub permissions: PermissionSet,
+
+    /// Notes about the Role, up to 255 chars
+    #[builder(default, setter(into))]
+    pub notes: Option<String>,
+
+    /// Is the Role active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Create<Role> for CreateRole {
+    fn into_resource(self, role_id: u64) -> Role {
+        Role {
+            role_id,
+            role_name: self.role_name,
+            permissions: self.permissions,
+            notes: self.notes,
+            active: self.active,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TypedBuilder)]
+pub struct DeleteRole {
+    role_id: u64,
+}
+
+impl Delete<Role> for DeleteRole {}
+
+impl Delete<Role> for Role {}
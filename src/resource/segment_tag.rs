@@ -0,0 +1,111 @@
+//! A Segment Tag is the markup to place on a page to add its visitors to a Segment, such as an image
+//! pixel or a script tag. Tags are generated by Buzz for a given Segment and tag type, so only GET
+//! requests are supported.
+
+use crate::client::{async_client::impl_async_clients, sync_client::SyncClient};
+use crate::resource::{common::EventTagType, Read, Resource};
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct SegmentTag {
+    /// The Segment the tag adds visitors to
+    pub segment_id: u64,
+
+    /// The kind of tag
+    pub tag_type: EventTagType,
+
+    /// Does the tag use https?
+    pub secure: Option<bool>,
+
+    /// The markup to place on the page
+    pub tag: String,
+}
+
+impl SegmentTag {
+    /// Create a builder for ReadSegmentTag
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::{common::EventTagType, SegmentTag};
+    ///
+    /// let read_segment_tag = SegmentTag::read_builder()
+    ///     .segment_id(1)
+    ///     .tag_type(EventTagType::JavaScript)
+    ///     .build();
+    ///
+    /// let segment_tags = beeswax_client.read(&read_segment_tag).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadSegmentTagBuilder<((), (), ())> {
+        ReadSegmentTag::builder()
+    }
+}
+
+impl Resource for SegmentTag {
+    const NAME: &'static str = "segment_tag";
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadSegmentTag {
+    /// The Segment the tag adds visitors to
+    #[builder(default, setter(into))]
+    pub segment_id: Option<u64>,
+    /// The kind of tag
+    #[builder(default, setter(into))]
+    pub tag_type: Option<EventTagType>,
+    /// Does the tag use https?
+    #[builder(default, setter(into))]
+    pub secure: Option<bool>,
+}
+
+impl Read<SegmentTag> for ReadSegmentTag {}
+
+impl ReadSegmentTag {
+    /// Search criteria for the tag of a Segment of the given type
+    pub fn new(segment_id: u64, tag_type: EventTagType) -> ReadSegmentTag {
+        ReadSegmentTag {
+            segment_id: Some(segment_id),
+            tag_type: Some(tag_type),
+            ..Default::default()
+        }
+    }
+
+    /// Fetch the markup of the first matching tag
+    pub fn markup_sync<C: SyncClient>(&self, beeswax_client: &C) -> Result<String> {
+        first_markup(self.read_sync(beeswax_client)?, self)
+    }
+}
+
+fn first_markup(segment_tags: Vec<SegmentTag>, criteria: &ReadSegmentTag) -> Result<String> {
+    segment_tags
+        .into_iter()
+        .next()
+        .map(|segment_tag| segment_tag.tag)
+        .ok_or_else(|| format!("no segment tag found for {:?}", criteria).into())
+}
+
+impl_async_clients! {
+    /// Fetch the markup of a Segment's tag of the given type
+    pub async fn segment_tag_markup(
+        &self,
+        segment_id: u64,
+        tag_type: EventTagType,
+    ) -> Result<String> {
+        let read_segment_tag = ReadSegmentTag::new(segment_id, tag_type);
+        first_markup(self.read(&read_segment_tag).await?, &read_segment_tag)
+    }
+}
+
+impl PartialEq<SegmentTag> for ReadSegmentTag {
+    fn eq(&self, other: &SegmentTag) -> bool {
+        (self.segment_id.is_none() || self.segment_id == Some(other.segment_id))
+            && (self.tag_type.is_none() || self.tag_type == Some(other.tag_type))
+            && (self.secure.is_none() || self.secure == other.secure)
+    }
+}
@@ -0,0 +1,189 @@
+//! A Test Group is a share of the users of a Test Plan. Users are assigned to groups at random, and
+//! each Line Item of a Campaign using the Test Plan serves only to the users in its test_group_id.
+//! A holdout is a Test Group with no Line Items.
+
+use crate::resource::{Create, Delete, Read, Resource};
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct TestGroup {
+    /// Unique ID of the Test Group
+    pub test_group_id: u64,
+
+    /// The Test Plan the Test Group belongs to
+    pub test_plan_id: u64,
+
+    /// Name of the Test Group, e.g. "Control"
+    pub test_group_name: String,
+
+    /// Percentage of the Test Plan's users in the group, from 0 to 100. The groups of a Test Plan
+    /// can add up to at most 100.
+    pub percentage: u8,
+
+    /// An alternative id to lookup the Test Group, if desired
+    pub alternative_id: Option<String>,
+
+    /// Notes about the Test Group, up to 255 chars
+    pub notes: Option<String>,
+
+    /// Is the Test Group active?
+    pub active: Option<bool>,
+
+    // Undocumented
+    pub account_id: Option<u64>,
+    pub create_date: Option<String>,
+    pub update_date: Option<String>,
+}
+
+impl TestGroup {
+    /// Create a builder for CreateTestGroup
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::TestGroup;
+    ///
+    /// let create_test_group = TestGroup::create_builder()
+    ///     .test_plan_id(1)
+    ///     .test_group_name("Control")
+    ///     .percentage(10)
+    ///     .build();
+    ///
+    /// let test_group = beeswax_client.create(&create_test_group).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_builder() -> CreateTestGroupBuilder<((), (), (), (), (), ())> {
+        CreateTestGroup::builder()
+    }
+
+    /// Create a builder for ReadTestGroup
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::TestGroup;
+    ///
+    /// let read_test_group = TestGroup::read_builder()
+    ///     .test_plan_id(1)
+    ///     .build();
+    ///
+    /// let test_groups = beeswax_client.read(&read_test_group).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadTestGroupBuilder<((), (), (), (), ())> {
+        ReadTestGroup::builder()
+    }
+
+    /// Create a builder for DeleteTestGroup
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::TestGroup;
+    ///
+    /// let delete_test_group = TestGroup::delete_builder()
+    ///     .test_group_id(10)
+    ///     .build();
+    ///
+    /// beeswax_client.delete(&delete_test_group).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_builder() -> DeleteTestGroupBuilder<((),)> {
+        DeleteTestGroup::builder()
+    }
+}
+
+impl Resource for TestGroup {
+    const NAME: &'static str = "test_group";
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadTestGroup {
+    /// Unique ID of the Test Group
+    #[builder(default, setter(into))]
+    pub test_group_id: Option<u64>,
+    /// The Test Plan the Test Group belongs to
+    #[builder(default, setter(into))]
+    pub test_plan_id: Option<u64>,
+    /// Name of the Test Group. Supports %LIKE% syntax
+    #[builder(default, setter(into))]
+    pub test_group_name: Option<String>,
+    /// An alternative id to lookup the Test Group, if desired
+    #[builder(default, setter(into))]
+    pub alternative_id: Option<String>,
+    /// Is the Test Group active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Read<TestGroup> for ReadTestGroup {}
+
+impl PartialEq<TestGroup> for ReadTestGroup {
+    fn eq(&self, other: &TestGroup) -> bool {
+        (self.test_group_id.is_none() || self.test_group_id == Some(other.test_group_id))
+            && (self.test_plan_id.is_none() || self.test_plan_id == Some(other.test_plan_id))
+            && (self.test_group_name.is_none()
+                || self.test_group_name.as_ref() == Some(&other.test_group_name))
+            && (self.alternative_id.is_none() || self.alternative_id == other.alternative_id)
+            && (self.active.is_none() || self.active == other.active)
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct CreateTestGroup {
+    /// The Test Plan the Test Group belongs to
+    pub test_plan_id: u64,
+
+    /// Name of the Test Group, e.g. "Control"
+    #[builder(setter(into))]
+    pub test_group_name: String,
+
+    /// Percentage of the Test Plan's users in the group, from 0 to 100
+    pub percentage: u8,
+
+    /// An alternative id to lookup the Test Group, if desired
+    #[builder(default, setter(into))]
+    pub alternative_id: Option<String>,
+
+    /// Notes about the Test Group, up to 255 chars
+    #[builder(default, setter(into))]
+    pub notes: Option<String>,
+
+    /// Is the Test Group active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Create<TestGroup> for CreateTestGroup {
+    fn into_resource(self, test_group_id: u64) -> TestGroup {
+        TestGroup {
+            test_group_id,
+            test_plan_id: self.test_plan_id,
+            test_group_name: self.test_group_name,
+            percentage: self.percentage,
+            alternative_id: self.alternative_id,
+            notes: self.notes,
+            active: self.active,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TypedBuilder)]
+pub struct DeleteTestGroup {
+    test_group_id: u64,
+}
+
+impl Delete<TestGroup> for DeleteTestGroup {}
+
+impl Delete<TestGroup> for TestGroup {}
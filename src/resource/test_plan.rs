@@ -0,0 +1,208 @@
+//! A Test Plan splits users into Test Groups for experiments, such as A/B tests or holdouts. A
+//! Campaign is assigned a Test Plan with test_plan_id, and each of its Line Items then serves only
+//! to the users of one Test Group.
+
+use crate::resource::{test_group::TestGroup, Create, Delete, Read, Resource};
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use typed_builder::TypedBuilder;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct TestPlan {
+    /// Unique ID of the Test Plan
+    pub test_plan_id: u64,
+
+    /// Name of the Test Plan, e.g. "Retargeting holdout"
+    pub test_plan_name: String,
+
+    /// The Advertiser the Test Plan may be used by. If empty, every Advertiser may use the plan.
+    pub advertiser_id: Option<u64>,
+
+    /// An alternative id to lookup the Test Plan, if desired
+    pub alternative_id: Option<String>,
+
+    /// Notes about the Test Plan, up to 255 chars
+    pub notes: Option<String>,
+
+    /// Is the Test Plan active?
+    pub active: Option<bool>,
+
+    // Undocumented
+    pub account_id: Option<u64>,
+    pub create_date: Option<String>,
+    pub update_date: Option<String>,
+}
+
+impl TestPlan {
+    /// Create a builder for CreateTestPlan
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::TestPlan;
+    ///
+    /// let create_test_plan = TestPlan::create_builder()
+    ///     .test_plan_name("Retargeting holdout")
+    ///     .build();
+    ///
+    /// let test_plan = beeswax_client.create(&create_test_plan).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_builder() -> CreateTestPlanBuilder<((), (), (), (), ())> {
+        CreateTestPlan::builder()
+    }
+
+    /// Create a builder for ReadTestPlan
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::TestPlan;
+    ///
+    /// let read_test_plan = TestPlan::read_builder()
+    ///     .test_plan_name("Retargeting holdout".to_string())
+    ///     .build();
+    ///
+    /// let test_plans = beeswax_client.read(&read_test_plan).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadTestPlanBuilder<((), (), (), (), ())> {
+        ReadTestPlan::builder()
+    }
+
+    /// Create a builder for DeleteTestPlan
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::TestPlan;
+    ///
+    /// let delete_test_plan = TestPlan::delete_builder()
+    ///     .test_plan_id(10)
+    ///     .build();
+    ///
+    /// beeswax_client.delete(&delete_test_plan).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_builder() -> DeleteTestPlanBuilder<((),)> {
+        DeleteTestPlan::builder()
+    }
+
+    /// Check the Test Groups belong to this Test Plan and don't split more than 100% of users
+    /// between them
+    pub fn validate_groups(&self, test_groups: &[TestGroup]) -> Result<()> {
+        if let Some(test_group) = test_groups
+            .iter()
+            .find(|test_group| test_group.test_plan_id != self.test_plan_id)
+        {
+            return Err(format!(
+                "test group {} belongs to test plan {} not {}",
+                test_group.test_group_id, test_group.test_plan_id, self.test_plan_id
+            )
+            .into());
+        }
+        let percentage: u64 = test_groups
+            .iter()
+            .map(|test_group| u64::from(test_group.percentage))
+            .sum();
+        if percentage > 100 {
+            return Err(format!(
+                "test groups of test plan {} add up to {}% of users",
+                self.test_plan_id, percentage
+            )
+            .into());
+        }
+        Ok(())
+    }
+}
+
+impl Resource for TestPlan {
+    const NAME: &'static str = "test_plan";
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadTestPlan {
+    /// Unique ID of the Test Plan
+    #[builder(default, setter(into))]
+    pub test_plan_id: Option<u64>,
+    /// Name of the Test Plan. Supports %LIKE% syntax
+    #[builder(default, setter(into))]
+    pub test_plan_name: Option<String>,
+    /// The Advertiser the Test Plan may be used by
+    #[builder(default, setter(into))]
+    pub advertiser_id: Option<u64>,
+    /// An alternative id to lookup the Test Plan, if desired
+    #[builder(default, setter(into))]
+    pub alternative_id: Option<String>,
+    /// Is the Test Plan active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Read<TestPlan> for ReadTestPlan {}
+
+impl PartialEq<TestPlan> for ReadTestPlan {
+    fn eq(&self, other: &TestPlan) -> bool {
+        (self.test_plan_id.is_none() || self.test_plan_id == Some(other.test_plan_id))
+            && (self.test_plan_name.is_none()
+                || self.test_plan_name.as_ref() == Some(&other.test_plan_name))
+            && (self.advertiser_id.is_none() || self.advertiser_id == other.advertiser_id)
+            && (self.alternative_id.is_none() || self.alternative_id == other.alternative_id)
+            && (self.active.is_none() || self.active == other.active)
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct CreateTestPlan {
+    /// Name of the Test Plan, e.g. "Retargeting holdout"
+    #[builder(setter(into))]
+    pub test_plan_name: String,
+
+    /// The Advertiser the Test Plan may be used by. If empty, every Advertiser may use the plan.
+    #[builder(default, setter(into))]
+    pub advertiser_id: Option<u64>,
+
+    /// An alternative id to lookup the Test Plan, if desired
+    #[builder(default, setter(into))]
+    pub alternative_id: Option<String>,
+
+    /// Notes about the Test Plan, up to 255 chars
+    #[builder(default, setter(into))]
+    pub notes: Option<String>,
+
+    /// Is the Test Plan active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Create<TestPlan> for CreateTestPlan {
+    fn into_resource(self, test_plan_id: u64) -> TestPlan {
+        TestPlan {
+            test_plan_id,
+            test_plan_name: self.test_plan_name,
+            advertiser_id: self.advertiser_id,
+            alternative_id: self.alternative_id,
+            notes: self.notes,
+            active: self.active,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TypedBuilder)]
+pub struct DeleteTestPlan {
+    test_plan_id: u64,
+}
+
+impl Delete<TestPlan> for DeleteTestPlan {}
+
+impl Delete<TestPlan> for TestPlan {}
@@ -0,0 +1,226 @@
+//! A User is a person who can log in to Buzz. Every User belongs to an Account and has a Role
+//! granting their permissions. A new User's email and password can be used with Authenticate to log
+//! in as them.
+
+use crate::resource::{authenticate::Authenticate, Create, Delete, Read, Resource};
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+use typed_builder::TypedBuilder;
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct User {
+    /// Unique ID of the User
+    pub user_id: u64,
+
+    /// Email address of the User, used to log in. Must be unique.
+    pub email: String,
+
+    /// First name of the User
+    pub first_name: Option<String>,
+
+    /// Last name of the User
+    pub last_name: Option<String>,
+
+    /// The Role granting the User's permissions
+    pub role_id: u64,
+
+    /// May the User log in to every Account on the Buzz instance?
+    pub all_account_access: Option<bool>,
+
+    /// Is the User a super user, with every permission?
+    pub super_user: Option<bool>,
+
+    /// Is the User active? Inactive Users cannot log in.
+    pub active: Option<bool>,
+
+    // Undocumented
+    pub account_id: Option<u64>,
+    pub create_date: Option<String>,
+    pub update_date: Option<String>,
+}
+
+impl User {
+    /// Create a builder for CreateUser
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::User;
+    ///
+    /// let create_user = User::create_builder()
+    ///     .email("trader@example.com")
+    ///     .role_id(1)
+    ///     .password("correct horse battery staple".to_string())
+    ///     .build();
+    ///
+    /// let user = beeswax_client.create(&create_user).await?;
+    /// let authenticate = create_user.authenticate()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn create_builder() -> CreateUserBuilder<((), (), (), (), (), (), (), (), ())> {
+        CreateUser::builder()
+    }
+
+    /// Create a builder for ReadUser
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::User;
+    ///
+    /// let read_user = User::read_builder()
+    ///     .email("trader@example.com".to_string())
+    ///     .build();
+    ///
+    /// let users = beeswax_client.read(&read_user).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadUserBuilder<((), (), (), ())> {
+        ReadUser::builder()
+    }
+
+    /// Create a builder for DeleteUser
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::User;
+    ///
+    /// let delete_user = User::delete_builder()
+    ///     .user_id(10)
+    ///     .build();
+    ///
+    /// beeswax_client.delete(&delete_user).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_builder() -> DeleteUserBuilder<((),)> {
+        DeleteUser::builder()
+    }
+}
+
+impl Resource for User {
+    const NAME: &'static str = "user";
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadUser {
+    /// Unique ID of the User
+    #[builder(default, setter(into))]
+    pub user_id: Option<u64>,
+    /// Email address of the User. Supports %LIKE% syntax
+    #[builder(default, setter(into))]
+    pub email: Option<String>,
+    /// The Role granting the User's permissions
+    #[builder(default, setter(into))]
+    pub role_id: Option<u64>,
+    /// Is the User active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Read<User> for ReadUser {}
+
+impl PartialEq<User> for ReadUser {
+    fn eq(&self, other: &User) -> bool {
+        (self.user_id.is_none() || self.user_id == Some(other.user_id))
+            && (self.email.is_none() || self.email.as_ref() == Some(&other.email))
+            && (self.role_id.is_none() || self.role_id == Some(other.role_id))
+            && (self.active.is_none() || self.active == other.active)
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct CreateUser {
+    /// Email address of the User, used to log in. Must be unique.
+    #[builder(setter(into))]
+    pub email: String,
+
+    /// First name of the User
+    #[builder(default, setter(into))]
+    pub first_name: Option<String>,
+
+    /// Last name of the User
+    #[builder(default, setter(into))]
+    pub last_name: Option<String>,
+
+    /// The Role granting the User's permissions
+    pub role_id: u64,
+
+    /// Password of the User. If not set, the User is emailed a link to choose one.
+    #[builder(default, setter(into))]
+    pub password: Option<String>,
+
+    /// The Account the User belongs to, if not the Account being used
+    #[builder(default, setter(into))]
+    pub account_id: Option<u64>,
+
+    /// May the User log in to every Account on the Buzz instance?
+    #[builder(default, setter(into))]
+    pub all_account_access: Option<bool>,
+
+    /// Is the User a super user, with every permission?
+    #[builder(default, setter(into))]
+    pub super_user: Option<bool>,
+
+    /// Is the User active? Inactive Users cannot log in.
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl CreateUser {
+    /// Authentication to log in as the new User, if a password was set. Returns an error if the
+    /// account_id is too large to authenticate with.
+    pub fn authenticate(&self) -> Result<Option<Authenticate>> {
+        let password = match &self.password {
+            Some(password) => password.clone(),
+            None => return Ok(None),
+        };
+        let account_id = match self.account_id {
+            Some(account_id) => Some(i32::try_from(account_id).map_err(|_| {
+                format!("account {} is too large to authenticate with", account_id)
+            })?),
+            None => None,
+        };
+        Ok(Some(Authenticate {
+            account_id,
+            ..Authenticate::simple(self.email.clone(), password)
+        }))
+    }
+}
+
+impl Create<User> for CreateUser {
+    fn into_resource(self, user_id: u64) -> User {
+        User {
+            user_id,
+            email: self.email,
+            first_name: self.first_name,
+            last_name: self.last_name,
+            role_id: self.role_id,
+            all_account_access: self.all_account_access,
+            super_user: self.super_user,
+            active: self.active,
+            account_id: self.account_id,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TypedBuilder)]
+pub struct DeleteUser {
+    user_id: u64,
+}
+
+impl Delete<User> for DeleteUser {}
+
+impl Delete<User> for User {}
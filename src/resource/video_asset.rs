@@ -0,0 +1,485 @@
+//! A Video Asset is a video file uploaded to Buzz for use in Video Creatives. Once uploaded the
+//! video is transcoded into the formats listed in the video_encoding_profiles view, and can be used
+//! as the primary_asset of a Creative once transcoding is complete. Videos waiting to be transcoded
+//! can be seen in the video_creative_transcoding_queue view.
+
+use crate::client::{async_client::impl_async_clients, sync_client::SyncClient, PollOptions};
+use crate::resource::{
+    common::CreativeType,
+    creative::{CreateCreative, Creative},
+    Create, Delete, Read, Resource, Upload,
+};
+use crate::Result;
+use serde::{Deserialize, Serialize};
+use std::{fs, path::Path};
+use typed_builder::TypedBuilder;
+
+#[derive(Copy, Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum TranscodeStatus {
+    /// The video is waiting in the transcoding queue
+    Queued,
+    /// The video is currently being transcoded
+    Processing,
+    /// The video has been transcoded and can be used in a Creative
+    Complete,
+    /// The video could not be transcoded
+    Failed,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct VideoAsset {
+    /// Unique ID of the Video Asset
+    pub video_asset_id: u64,
+
+    /// Must belong to the same account as the Video Asset and be active
+    pub advertiser_id: u64,
+
+    /// Name of the Video Asset, e.g. "Winter sale 30s"
+    pub video_asset_name: String,
+
+    /// The URL the video was uploaded from, if it was not uploaded directly
+    pub source_url: Option<String>,
+
+    /// Size of the video file in bytes
+    pub size_in_bytes: Option<u64>,
+
+    /// Mime type of the video. Use the mime_types view to see all acceptable mime types.
+    pub mime_type: Option<String>,
+
+    /// Duration of the video in seconds, set by Buzz after upload
+    pub duration: Option<f64>,
+
+    /// Bitrate of the video in kbps, set by Buzz after upload
+    pub bitrate: Option<u64>,
+
+    /// Width of the video in pixels, set by Buzz after upload
+    pub width: Option<u64>,
+
+    /// Height of the video in pixels, set by Buzz after upload
+    pub height: Option<u64>,
+
+    /// URL of a thumbnail taken from the video, set by Buzz once transcoded
+    pub thumbnail_url: Option<String>,
+
+    /// Notes about the Video Asset, up to 255 chars
+    pub notes: Option<String>,
+
+    /// Is the Video Asset active?
+    pub active: Option<bool>,
+
+    // Undocumented
+    pub transcode_status: Option<TranscodeStatus>,
+    pub transcode_error: Option<String>,
+    pub account_id: Option<u64>,
+    pub create_date: Option<String>,
+    pub update_date: Option<String>,
+    pub buzz_key: Option<String>,
+}
+
+impl VideoAsset {
+    /// Create a builder for CreateVideoAsset
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::VideoAsset;
+    ///
+    /// let create_video_asset = VideoAsset::create_builder()
+    ///     .advertiser_id(1)
+    ///     .video_asset_name("Some name")
+    ///     .build();
+    ///
+    /// let video_asset = beeswax_client.create(&create_video_asset).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_builder() -> CreateVideoAssetBuilder<((), (), (), (), (), (), ())> {
+        CreateVideoAsset::builder()
+    }
+
+    /// Create a builder for ReadVideoAsset
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::VideoAsset;
+    ///
+    /// let read_video_asset = VideoAsset::read_builder()
+    ///     .advertiser_id(1)
+    ///     .build();
+    ///
+    /// let video_assets = beeswax_client.read(&read_video_asset).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadVideoAssetBuilder<((), (), (), (), ())> {
+        ReadVideoAsset::builder()
+    }
+
+    /// Create a builder for DeleteVideoAsset
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::VideoAsset;
+    ///
+    /// let delete_video_asset = VideoAsset::delete_builder()
+    ///     .video_asset_id(10)
+    ///     .build();
+    ///
+    /// beeswax_client.delete(&delete_video_asset).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn delete_builder() -> DeleteVideoAssetBuilder<((),)> {
+        DeleteVideoAsset::builder()
+    }
+
+    /// Create a builder for UploadVideoAsset
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let mut beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::VideoAsset;
+    ///
+    /// let upload_video_asset = VideoAsset::upload_builder()
+    ///     .video_asset_id(10)
+    ///     .file_name("winter_sale.mp4")
+    ///     .content(vec![0, 0, 0, 24])
+    ///     .build();
+    ///
+    /// beeswax_client.upload(&upload_video_asset).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn upload_builder() -> UploadVideoAssetBuilder<((), (), ())> {
+        UploadVideoAsset::builder()
+    }
+
+    /// Check whether Buzz has finished transcoding the video. Assets with no transcode status have
+    /// not been reported as transcoded yet, so are treated as not ready. Returns an error if
+    /// transcoding failed.
+    pub fn is_transcoded(&self) -> Result<bool> {
+        match self.transcode_status {
+            Some(TranscodeStatus::Complete) => Ok(true),
+            None | Some(TranscodeStatus::Queued) | Some(TranscodeStatus::Processing) => Ok(false),
+            Some(TranscodeStatus::Failed) => Err(format!(
+                "video asset {} failed to transcode: {}",
+                self.video_asset_id,
+                self.transcode_error.as_deref().unwrap_or("unknown error")
+            )
+            .into()),
+        }
+    }
+
+    /// Poll Buzz until this video has finished transcoding, returning the transcoded asset
+    pub fn wait_for_transcode_sync<C: SyncClient>(
+        &self,
+        beeswax_client: &C,
+        poll: PollOptions,
+    ) -> Result<VideoAsset> {
+        let read_video_asset = ReadVideoAsset::from(self);
+        let video_asset = poll.poll_sync(|| {
+            let video_asset = read_video_asset.read_one_sync(beeswax_client)?;
+            transcoded(video_asset)
+        })?;
+        video_asset.ok_or_else(|| transcode_timeout(self.video_asset_id, poll))
+    }
+}
+
+impl Resource for VideoAsset {
+    const NAME: &'static str = "video_asset";
+}
+
+fn transcoded(video_asset: VideoAsset) -> Result<Option<VideoAsset>> {
+    if video_asset.is_transcoded()? {
+        Ok(Some(video_asset))
+    } else {
+        Ok(None)
+    }
+}
+
+fn transcode_timeout(video_asset_id: u64, poll: PollOptions) -> Box<dyn std::error::Error> {
+    format!(
+        "video asset {} was not transcoded after {} attempts",
+        video_asset_id, poll.max_attempts
+    )
+    .into()
+}
+
+#[derive(Clone, Debug, Default, Serialize, TypedBuilder)]
+pub struct ReadVideoAsset {
+    /// Unique ID of the Video Asset
+    #[builder(default, setter(into))]
+    pub video_asset_id: Option<u64>,
+    /// Must belong to the same account as the Video Asset
+    #[builder(default, setter(into))]
+    pub advertiser_id: Option<u64>,
+    /// Name of the Video Asset. Supports %LIKE% syntax
+    #[builder(default, setter(into))]
+    pub video_asset_name: Option<String>,
+    /// Mime type of the video
+    #[builder(default, setter(into))]
+    pub mime_type: Option<String>,
+    /// Is the Video Asset active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl ReadVideoAsset {
+    fn read_one_sync<C: SyncClient>(&self, beeswax_client: &C) -> Result<VideoAsset> {
+        self.read_sync(beeswax_client)?
+            .pop()
+            .ok_or_else(|| "video asset not found".into())
+    }
+}
+
+impl Read<VideoAsset> for ReadVideoAsset {}
+
+impl PartialEq<VideoAsset> for ReadVideoAsset {
+    fn eq(&self, other: &VideoAsset) -> bool {
+        (self.video_asset_id.is_none() || self.video_asset_id == Some(other.video_asset_id))
+            && (self.advertiser_id.is_none() || self.advertiser_id == Some(other.advertiser_id))
+            && (self.video_asset_name.is_none()
+                || self.video_asset_name.as_ref() == Some(&other.video_asset_name))
+            && (self.mime_type.is_none() || self.mime_type == other.mime_type)
+            && (self.active.is_none() || self.active == other.active)
+    }
+}
+
+/// Create a search criteria for a specific Video Asset
+impl From<&VideoAsset> for ReadVideoAsset {
+    fn from(video_asset: &VideoAsset) -> Self {
+        ReadVideoAsset {
+            video_asset_id: Some(video_asset.video_asset_id),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize, TypedBuilder)]
+pub struct CreateVideoAsset {
+    /// Must belong to the same account as the Video Asset and be active
+    pub advertiser_id: u64,
+
+    /// Name of the Video Asset, e.g. "Winter sale 30s"
+    #[builder(setter(into))]
+    pub video_asset_name: String,
+
+    /// The URL to fetch the video from, if it is not going to be uploaded directly
+    #[builder(default, setter(into))]
+    pub source_url: Option<String>,
+
+    /// Size of the video file in bytes
+    #[builder(default, setter(into))]
+    pub size_in_bytes: Option<u64>,
+
+    /// Mime type of the video. Use the mime_types view to see all acceptable mime types.
+    #[builder(default, setter(into))]
+    pub mime_type: Option<String>,
+
+    /// Notes about the Video Asset, up to 255 chars
+    #[builder(default, setter(into))]
+    pub notes: Option<String>,
+
+    /// Is the Video Asset active?
+    #[builder(default, setter(into))]
+    pub active: Option<bool>,
+}
+
+impl Create<VideoAsset> for CreateVideoAsset {
+    fn into_resource(self, video_asset_id: u64) -> VideoAsset {
+        VideoAsset {
+            video_asset_id,
+            advertiser_id: self.advertiser_id,
+            video_asset_name: self.video_asset_name,
+            source_url: self.source_url,
+            size_in_bytes: self.size_in_bytes,
+            mime_type: self.mime_type,
+            notes: self.notes,
+            active: self.active,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, TypedBuilder)]
+pub struct DeleteVideoAsset {
+    video_asset_id: u64,
+}
+
+impl Delete<VideoAsset> for DeleteVideoAsset {}
+
+impl Delete<VideoAsset> for VideoAsset {}
+
+#[derive(Clone, Debug, TypedBuilder)]
+pub struct UploadVideoAsset {
+    /// Unique ID of the Video Asset the file belongs to
+    pub video_asset_id: u64,
+    /// Name of the file, e.g. "winter_sale.mp4"
+    #[builder(setter(into))]
+    pub file_name: String,
+    /// Raw content of the video file
+    pub content: Vec<u8>,
+}
+
+impl UploadVideoAsset {
+    /// Read a video file from disk ready to upload to the given Video Asset
+    pub fn from_path<P: AsRef<Path>>(video_asset_id: u64, path: P) -> Result<UploadVideoAsset> {
+        let path = path.as_ref();
+        let file_name = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or("video path has no file name")?
+            .to_string();
+        Ok(UploadVideoAsset {
+            video_asset_id,
+            file_name,
+            content: fs::read(path)?,
+        })
+    }
+}
+
+impl Upload<VideoAsset> for UploadVideoAsset {
+    const FIELD_NAME: &'static str = "creative_content";
+
+    fn upload_id(&self) -> u64 {
+        self.video_asset_id
+    }
+
+    fn file_name(&self) -> &str {
+        &self.file_name
+    }
+
+    fn file_content(&self) -> &[u8] {
+        &self.content
+    }
+
+    /// The in-memory clients transcode videos as soon as they are uploaded
+    fn upload_into(&self, video_asset: &mut VideoAsset) -> Result<()> {
+        video_asset.transcode_status = Some(TranscodeStatus::Complete);
+        Ok(())
+    }
+}
+
+impl CreateVideoAsset {
+    /// Create the Video Asset, upload the video, wait for it to be transcoded and then create a
+    /// Video Creative using it as the primary asset
+    pub fn create_video_creative_sync<C: SyncClient>(
+        &self,
+        beeswax_client: &C,
+        file_name: &str,
+        content: Vec<u8>,
+        create_creative: &CreateCreative,
+        poll: PollOptions,
+    ) -> Result<Creative> {
+        let video_asset = self.create_sync(beeswax_client)?;
+        UploadVideoAsset {
+            video_asset_id: video_asset.video_asset_id,
+            file_name: file_name.to_string(),
+            content,
+        }
+        .upload_sync(beeswax_client)?;
+        let video_asset = video_asset.wait_for_transcode_sync(beeswax_client, poll)?;
+        video_creative(&video_asset, create_creative).create_sync(beeswax_client)
+    }
+}
+
+/// Use the transcoded Video Asset as the primary asset of a Video Creative
+fn video_creative(video_asset: &VideoAsset, create_creative: &CreateCreative) -> CreateCreative {
+    let mut create_creative = create_creative.clone();
+    create_creative.creative_type = CreativeType::Video;
+    create_creative.primary_asset = Some(video_asset.video_asset_id);
+    if create_creative.creative_thumbnail_url.is_none() {
+        create_creative.creative_thumbnail_url = video_asset.thumbnail_url.clone();
+    }
+    create_creative
+}
+
+impl_async_clients! {
+    /// Poll until the given video has finished transcoding, returning the transcoded asset
+    pub async fn wait_for_transcode(
+        &self,
+        video_asset: &VideoAsset,
+        poll: PollOptions,
+    ) -> Result<VideoAsset> {
+        let read_video_asset = ReadVideoAsset::from(video_asset);
+        let read_video_asset = &read_video_asset;
+        let transcoded = poll
+            .poll_async(|| async move {
+                let video_asset = self
+                    .read(read_video_asset)
+                    .await?
+                    .pop()
+                    .ok_or("video asset not found")?;
+                transcoded(video_asset)
+            })
+            .await?;
+        transcoded.ok_or_else(|| transcode_timeout(video_asset.video_asset_id, poll))
+    }
+
+    /// Create the Video Asset, upload the video, wait for it to be transcoded and then create a
+    /// Video Creative using it as the primary asset
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::client::PollOptions;
+    /// use beeswax::resource::{common::CreativeType, Creative, VideoAsset};
+    ///
+    /// let create_video_asset = VideoAsset::create_builder()
+    ///     .advertiser_id(1)
+    ///     .video_asset_name("Winter sale 30s")
+    ///     .build();
+    ///
+    /// let create_creative = Creative::create_builder()
+    ///     .advertiser_id(1)
+    ///     .creative_name("Winter sale video")
+    ///     .build();
+    ///
+    /// let creative = beeswax_client
+    ///     .create_video_creative(
+    ///         &create_video_asset,
+    ///         "winter_sale.mp4",
+    ///         vec![0, 0, 0, 24],
+    ///         &create_creative,
+    ///         PollOptions::default(),
+    ///     )
+    ///     .await?;
+    ///
+    /// assert_eq!(creative.creative_type, CreativeType::Video);
+    /// assert!(creative.primary_asset.is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_video_creative(
+        &self,
+        create_video_asset: &CreateVideoAsset,
+        file_name: &str,
+        content: Vec<u8>,
+        create_creative: &CreateCreative,
+        poll: PollOptions,
+    ) -> Result<Creative> {
+        let video_asset = self.create(create_video_asset).await?;
+        let upload_video_asset = UploadVideoAsset {
+            video_asset_id: video_asset.video_asset_id,
+            file_name: file_name.to_string(),
+            content,
+        };
+        self.upload(&upload_video_asset).await?;
+        let video_asset = self.wait_for_transcode(&video_asset, poll).await?;
+        self.create(&video_creative(&video_asset, create_creative))
+            .await
+    }
+}
@@ -2,35 +2,208 @@
 //! useful for querying static tables to discover ad types. acceptable mime types, or other lookup
 //! fields. Views must be created by the Buzz administrator. Only GET requests are supported.
 
+use crate::client::async_client::impl_async_clients;
 use crate::resource::{common::ViewName, Read, Resource};
-use serde::{Deserialize, Serialize};
+use crate::Result;
+use serde::{de::DeserializeOwned, Deserialize, Serialize, Serializer};
 use serde_json::Value as JsonValue;
+use std::collections::HashMap;
 use std::ops::Deref;
+use typed_builder::TypedBuilder;
 
+/// A single row of a View. Rows read from Buzz don't know which View they came from, rows made
+/// with View::new do, so they can be served by the in-memory clients.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
-pub struct View(JsonValue);
+#[serde(from = "JsonValue", into = "JsonValue")]
+pub struct View {
+    row: JsonValue,
+    view_name: Option<String>,
+}
 
 impl Resource for View {
     const NAME: &'static str = "view";
 }
 
+impl View {
+    /// Create a row of the given View, e.g. to load canned data into an in-memory client
+    /// ```
+    /// use beeswax::client::sync_client::SyncInMemoryClient;
+    /// use beeswax::prelude::SyncClient;
+    /// use beeswax::resource::{common::ViewName, view_row::Country, View};
+    /// use serde_json::json;
+    ///
+    /// let beeswax_client = SyncInMemoryClient::new();
+    /// beeswax_client.insert(View::new(
+    ///     ViewName::Countries,
+    ///     json!({"country_code": "USA", "country_name": "United States"}),
+    /// ));
+    ///
+    /// let countries = beeswax_client.read_view::<Country>(ViewName::Countries).unwrap();
+    /// assert_eq!(countries[0].country_code, "USA");
+    /// ```
+    pub fn new(view_name: ViewName, row: JsonValue) -> View {
+        View {
+            row,
+            view_name: view_name_str(&view_name),
+        }
+    }
+
+    /// Create a builder for ReadView
+    /// ```
+    /// # use std::error::Error;
+    /// # use beeswax::client::async_client::AsyncInMemoryClient;
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn Error>> {
+    /// # let beeswax_client = AsyncInMemoryClient::new();
+    /// use beeswax::resource::{common::ViewName, View};
+    ///
+    /// let read_view = View::read_builder()
+    ///     .view_name(ViewName::LookupDomains)
+    ///     .fields(vec!["domain".to_string()])
+    ///     .rows(100)
+    ///     .build()
+    ///     .filter("domain", "example.com");
+    ///
+    /// let domains = beeswax_client.read(&read_view).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn read_builder() -> ReadViewBuilder<((), (), (), (), ())> {
+        ReadView::builder()
+    }
+
+    /// Deserialize the row into one of the typed rows in view_row, or any other matching type
+    pub fn into_row<T: DeserializeOwned>(self) -> Result<T> {
+        Ok(serde_json::from_value(self.row)?)
+    }
+}
+
 impl Deref for View {
     type Target = JsonValue;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.row
+    }
+}
+
+impl From<JsonValue> for View {
+    fn from(row: JsonValue) -> Self {
+        View {
+            row,
+            view_name: None,
+        }
+    }
+}
+
+impl From<View> for JsonValue {
+    fn from(view: View) -> Self {
+        view.row
     }
 }
 
-#[derive(Clone, Serialize)]
+pub(crate) fn view_name_str(view_name: &ViewName) -> Option<String> {
+    match serde_json::to_value(view_name) {
+        Ok(JsonValue::String(view_name)) => Some(view_name),
+        _ => None,
+    }
+}
+
+/// Render a field of a row the way it appears in a query string
+fn field_str(field: &JsonValue) -> String {
+    match field {
+        JsonValue::String(field) => field.clone(),
+        JsonValue::Null => String::new(),
+        field => field.to_string(),
+    }
+}
+
+fn comma_separated<S: Serializer>(
+    fields: &Option<Vec<String>>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error> {
+    match fields {
+        Some(fields) => serializer.serialize_some(&fields.join(",")),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[derive(Clone, Serialize, TypedBuilder)]
 pub struct ReadView {
+    /// The View to read
     pub view_name: ViewName,
+    /// Only include these fields in each row
+    #[builder(default, setter(into))]
+    #[serde(serialize_with = "comma_separated")]
+    pub fields: Option<Vec<String>>,
+    /// Maximum number of rows to return
+    #[builder(default, setter(into))]
+    pub rows: Option<u64>,
+    /// Number of rows to skip, for paging through large Views
+    #[builder(default, setter(into))]
+    pub offset: Option<u64>,
+    /// Only include rows whose field equals the given value, keyed by field
+    #[builder(default)]
+    #[serde(flatten)]
+    pub filters: HashMap<String, String>,
 }
 
-impl Read<View> for ReadView {}
+impl ReadView {
+    /// Only include rows whose field equals the given value
+    pub fn filter<F: Into<String>, V: Into<String>>(mut self, field: F, value: V) -> ReadView {
+        self.filters.insert(field.into(), value.into());
+        self
+    }
+}
+
+impl Read<View> for ReadView {
+    fn select(&self, views: Vec<View>) -> Vec<View> {
+        let offset = self.offset.unwrap_or(0) as usize;
+        let rows = self.rows.map(|rows| rows as usize).unwrap_or(usize::MAX);
+        views
+            .into_iter()
+            .skip(offset)
+            .take(rows)
+            .map(|mut view| {
+                if let (Some(fields), JsonValue::Object(row)) = (&self.fields, &mut view.row) {
+                    *row = std::mem::take(row)
+                        .into_iter()
+                        .filter(|(field, _)| fields.contains(field))
+                        .collect();
+                }
+                view
+            })
+            .collect()
+    }
+}
 
 impl PartialEq<View> for ReadView {
-    fn eq(&self, _other: &View) -> bool {
-        unreachable!("View is of unknown shape and should not get compared to ReadView")
+    fn eq(&self, other: &View) -> bool {
+        other.view_name.is_some()
+            && other.view_name == view_name_str(&self.view_name)
+            && self
+                .filters
+                .iter()
+                .all(|(field, value)| other.get(field).map(field_str).as_ref() == Some(value))
+    }
+}
+
+fn into_rows<T: DeserializeOwned>(views: Vec<View>) -> Result<Vec<T>> {
+    views.into_iter().map(View::into_row).collect()
+}
+
+impl_async_clients! {
+    /// Read every row of a View, deserializing each into the given type
+    pub async fn read_view<T: DeserializeOwned>(&self, view_name: ViewName) -> Result<Vec<T>> {
+        let read_view = ReadView::builder().view_name(view_name).build();
+        self.read_view_rows(&read_view).await
+    }
+
+    /// Read the rows of a View matching the search criteria, deserializing each into the given
+    /// type
+    pub async fn read_view_rows<T: DeserializeOwned>(
+        &self,
+        read_view: &ReadView,
+    ) -> Result<Vec<T>> {
+        into_rows(self.read(read_view).await?)
     }
 }
@@ -3,27 +3,169 @@
 //! populated in SQL by an administrator. The primary use case for this API call is to display the
 //! data from a view in a user interface. Only GET requests are supported.
 
+use crate::client::{async_client::impl_async_clients, sync_client::SyncClient};
 use crate::resource::common::ViewName;
+use crate::resource::view::{view_name_str, ReadView, View};
 use crate::resource::{Read, Resource};
+use crate::Result;
 use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 use std::ops::Deref;
 
+/// Metadata about a single field of a View. Rows read from Buzz don't know which View they came
+/// from, rows made with ViewList::new do, so they can be served by the in-memory clients.
 #[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
-pub struct ViewList(JsonValue);
+#[serde(from = "JsonValue", into = "JsonValue")]
+pub struct ViewList {
+    row: JsonValue,
+    view_name: Option<String>,
+}
 
 impl Resource for ViewList {
     const NAME: &'static str = "view_list";
 }
 
+impl ViewList {
+    /// Create the metadata of a field of the given View, e.g. to load canned data into an
+    /// in-memory client
+    pub fn new(view_name: ViewName, row: JsonValue) -> ViewList {
+        ViewList {
+            row,
+            view_name: view_name_str(&view_name),
+        }
+    }
+
+    /// Deserialize the metadata into a typed ViewListField
+    pub fn to_field(&self) -> Result<ViewListField> {
+        Ok(ViewListField::deserialize(&self.row)?)
+    }
+}
+
 impl Deref for ViewList {
     type Target = JsonValue;
 
     fn deref(&self) -> &Self::Target {
-        &self.0
+        &self.row
+    }
+}
+
+impl From<JsonValue> for ViewList {
+    fn from(row: JsonValue) -> Self {
+        ViewList {
+            row,
+            view_name: None,
+        }
     }
 }
 
+impl From<ViewList> for JsonValue {
+    fn from(view_list: ViewList) -> Self {
+        view_list.row
+    }
+}
+
+/// How a field should be displayed
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum DisplayType {
+    Text,
+    Number,
+    Currency,
+    Percent,
+    Date,
+    Boolean,
+    Link,
+    Image,
+    /// A display type not known to this crate
+    #[serde(other)]
+    Other,
+}
+
+/// Typed metadata about a single field of a View
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct ViewListField {
+    /// Name of the field in the View's rows
+    pub field_name: String,
+    /// Label to show for the field, e.g. "Country Name"
+    pub field_label: Option<String>,
+    /// How the field should be displayed
+    pub display_type: Option<DisplayType>,
+    /// Position of the field relative to the others, lowest first
+    pub sort_order: Option<i64>,
+}
+
+impl ViewListField {
+    /// The label to show for the field, falling back to its name
+    pub fn label(&self) -> &str {
+        self.field_label.as_deref().unwrap_or(&self.field_name)
+    }
+}
+
+/// A column of a View ready to display, with the field's metadata and its value in every row
+#[derive(Clone, Debug, PartialEq)]
+pub struct ViewColumn {
+    pub field: ViewListField,
+    pub values: Vec<JsonValue>,
+}
+
+/// Join the rows of a View with its View List into labelled columns. Columns follow the View
+/// List's sort order, then any fields missing from the View List follow in name order labelled
+/// by their name. Rows missing a field have a null value in its column.
+/// ```
+/// use beeswax::resource::view_list::{view_columns, ViewListField};
+/// use beeswax::resource::View;
+/// use serde_json::json;
+///
+/// let views: Vec<View> = serde_json::from_value(json!([
+///     {"country_code": "USA", "country_name": "United States"},
+/// ]))
+/// .unwrap();
+/// let fields = vec![ViewListField {
+///     field_name: "country_name".to_string(),
+///     field_label: Some("Country".to_string()),
+///     sort_order: Some(1),
+///     ..Default::default()
+/// }];
+///
+/// let columns = view_columns(&views, &fields);
+///
+/// assert_eq!(columns[0].field.label(), "Country");
+/// assert_eq!(columns[1].field.label(), "country_code");
+/// ```
+pub fn view_columns(views: &[View], fields: &[ViewListField]) -> Vec<ViewColumn> {
+    let mut fields = fields.to_vec();
+    fields.sort_by_key(|field| (field.sort_order.is_none(), field.sort_order));
+
+    let mut unlisted: Vec<String> = views
+        .iter()
+        .filter_map(|view| view.as_object())
+        .flat_map(|row| row.keys())
+        .filter(|field_name| !fields.iter().any(|field| &field.field_name == *field_name))
+        .cloned()
+        .collect();
+    unlisted.sort();
+    unlisted.dedup();
+    fields.extend(unlisted.into_iter().map(|field_name| ViewListField {
+        field_name,
+        ..Default::default()
+    }));
+
+    fields
+        .into_iter()
+        .map(|field| {
+            let values = views
+                .iter()
+                .map(|view| view.get(&field.field_name).cloned().unwrap_or_default())
+                .collect();
+            ViewColumn { field, values }
+        })
+        .collect()
+}
+
+fn into_fields(view_lists: Vec<ViewList>) -> Result<Vec<ViewListField>> {
+    view_lists.iter().map(ViewList::to_field).collect()
+}
+
 #[derive(Clone, Serialize)]
 pub struct ReadViewList {
     pub view_name: ViewName,
@@ -32,7 +174,31 @@ pub struct ReadViewList {
 impl Read<ViewList> for ReadViewList {}
 
 impl PartialEq<ViewList> for ReadViewList {
-    fn eq(&self, _other: &ViewList) -> bool {
-        unreachable!("ViewList is of unknown shape and should not get compared to ReadViewList")
+    fn eq(&self, other: &ViewList) -> bool {
+        other.view_name.is_some() && other.view_name == view_name_str(&self.view_name)
+    }
+}
+
+impl ReadView {
+    /// Read the rows of the View and its View List, joined into labelled columns
+    pub fn view_columns_sync<C: SyncClient>(&self, beeswax_client: &C) -> Result<Vec<ViewColumn>> {
+        let read_view_list = ReadViewList {
+            view_name: self.view_name.clone(),
+        };
+        let fields = into_fields(beeswax_client.read(&read_view_list)?)?;
+        let views = beeswax_client.read(self)?;
+        Ok(view_columns(&views, &fields))
+    }
+}
+
+impl_async_clients! {
+    /// Read the rows of a View and its View List, joined into labelled columns
+    pub async fn view_columns(&self, read_view: &ReadView) -> Result<Vec<ViewColumn>> {
+        let read_view_list = ReadViewList {
+            view_name: read_view.view_name.clone(),
+        };
+        let fields = into_fields(self.read(&read_view_list).await?)?;
+        let views = self.read(read_view).await?;
+        Ok(view_columns(&views, &fields))
     }
 }
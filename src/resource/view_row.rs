@@ -0,0 +1,131 @@
+//! Typed rows of the common lookup Views. Each struct holds a single row of the View named in its
+//! docs, and can be read with `read_view`, e.g. `read_view::<Country>(ViewName::Countries)`.
+
+use serde::{Deserialize, Serialize};
+
+/// A row of the countries and countries_active_view Views
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct Country {
+    /// ISO 3166-1 alpha-3 country code, e.g. "USA"
+    pub country_code: String,
+    /// Name of the country, e.g. "United States"
+    pub country_name: Option<String>,
+    /// ISO 3166-1 alpha-2 country code, e.g. "US"
+    pub country_code_2: Option<String>,
+    /// Continent the country is in, e.g. "NAM"
+    pub continent: Option<String>,
+}
+
+/// A row of the regions and regions_active_view Views
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct Region {
+    /// Code of the region prefixed by its country, e.g. "USA/NY"
+    pub region_code: String,
+    /// Name of the region, e.g. "New York"
+    pub region_name: Option<String>,
+    /// ISO 3166-1 alpha-3 code of the region's country
+    pub country_code: Option<String>,
+}
+
+/// A row of the cities and cities_active_view Views
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct City {
+    /// Unique code of the city, e.g. "USA/NY/New York"
+    pub city_code: String,
+    /// Name of the city, e.g. "New York"
+    pub city_name: Option<String>,
+    /// Code of the city's region, e.g. "USA/NY"
+    pub region_code: Option<String>,
+    /// ISO 3166-1 alpha-3 code of the city's country
+    pub country_code: Option<String>,
+}
+
+/// A row of the DMA View
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct Dma {
+    /// Google Metro code, e.g. 501
+    pub dma_code: u64,
+    /// Name of the metro area, e.g. "New York NY"
+    pub dma_name: Option<String>,
+}
+
+/// A row of the creative_sizes View
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct CreativeSize {
+    /// Width of the Creative in pixels
+    pub width: u64,
+    /// Height of the Creative in pixels
+    pub height: u64,
+    /// Name of the size, e.g. "Medium Rectangle"
+    pub size_name: Option<String>,
+    /// Type of Creative the size applies to, 0=banner, 1=video, 2=native
+    pub creative_type: Option<u64>,
+}
+
+/// A row of the mime_types View
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct MimeType {
+    /// The mime type, e.g. "image/png"
+    pub mime_type: String,
+    /// File extension of the mime type, e.g. "png"
+    pub extension: Option<String>,
+    /// Type of Creative Asset the mime type applies to, e.g. "image" or "video"
+    pub asset_type: Option<String>,
+}
+
+/// A row of the currency View
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct Currency {
+    /// ISO 4217 currency code, e.g. "USD"
+    pub currency_code: String,
+    /// Name of the currency, e.g. "US Dollar"
+    pub currency_name: Option<String>,
+    /// Symbol of the currency, e.g. "$"
+    pub currency_symbol: Option<String>,
+}
+
+/// A row of the inventory_source View
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct InventorySource {
+    /// Key of the inventory source used in targeting, e.g. "google"
+    pub inventory_source: String,
+    /// Name of the inventory source, e.g. "Google AdX"
+    pub inventory_source_name: Option<String>,
+    /// Is the inventory source available?
+    pub active: Option<bool>,
+}
+
+/// A row of the line_item_types View
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct LineItemType {
+    /// Unique ID of the Line Item type, 0=banner, 1=video, 2=native
+    pub line_item_type_id: u64,
+    /// Name of the Line Item type, e.g. "banner"
+    pub line_item_type_name: Option<String>,
+}
+
+/// A row of the bidding_strategies View
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct BiddingStrategy {
+    /// Name of the bidding strategy, as used by a Line Item's bidding, e.g. "CPM"
+    pub bidding_strategy: String,
+    /// Description of the bidding strategy
+    pub description: Option<String>,
+    /// The keys the bidding strategy accepts in its values, e.g. "cpm_bid"
+    pub keys: Option<Vec<String>>,
+    /// Is the bidding strategy custom to the account?
+    pub custom: Option<bool>,
+}
+
+/// A row of the creative_approval_vendors View
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct CreativeApprovalVendor {
+    /// Key of the exchange or vendor, as used by a Creative Approval, e.g. "google"
+    pub vendor: String,
+    /// Name of the exchange or vendor
+    pub vendor_name: Option<String>,
+    /// Must Creatives be approved by the vendor before they serve on it?
+    pub requires_approval: Option<bool>,
+    /// Is the vendor auditing Creatives?
+    pub active: Option<bool>,
+}
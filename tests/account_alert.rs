@@ -0,0 +1,79 @@
+extern crate beeswax;
+
+use beeswax::client::async_client::AsyncInMemoryClient;
+use beeswax::resource::{
+    account_alert::{CreateAccountAlert, DeleteAccountAlert, SystemAlertKey},
+    AccountAlert,
+};
+
+#[test]
+fn test_system_alert_key_json() {
+    let key: SystemAlertKey = serde_json::from_str(r#""bad_ad""#).unwrap();
+    assert_eq!(key, SystemAlertKey::BadAd);
+
+    let key: SystemAlertKey = serde_json::from_str(r#""pacing_behind""#).unwrap();
+    assert_eq!(key, SystemAlertKey::Custom("pacing_behind".to_string()));
+    assert_eq!(serde_json::to_string(&key).unwrap(), r#""pacing_behind""#);
+
+    assert_eq!(
+        serde_json::to_string(&SystemAlertKey::LineItemBudgetReached).unwrap(),
+        r#""line_item_budget_reached""#
+    );
+}
+
+#[test]
+fn test_create_account_alert_requires_key() {
+    let create: Result<CreateAccountAlert, _> =
+        serde_json::from_str(r#"{"email": "alerts@example.com"}"#);
+    assert!(create.is_err());
+
+    let create: CreateAccountAlert =
+        serde_json::from_str(r#"{"system_alert_key": "bad_ad", "email": "alerts@example.com"}"#)
+            .unwrap();
+    assert_eq!(create.system_alert_key, SystemAlertKey::BadAd);
+
+    let create = CreateAccountAlert::default();
+    assert_eq!(
+        serde_json::to_value(&create).unwrap()["system_alert_key"],
+        ""
+    );
+}
+
+#[tokio::test]
+async fn test_read_account_alert() {
+    let client = AsyncInMemoryClient::new();
+
+    let bad_ad = client
+        .create(
+            &AccountAlert::create_builder()
+                .system_alert_key(SystemAlertKey::BadAd)
+                .email("ops@example.com".to_string())
+                .build(),
+        )
+        .await
+        .unwrap();
+    client
+        .create(
+            &AccountAlert::create_builder()
+                .system_alert_key(SystemAlertKey::ReportFailed)
+                .slack_channel("#buzz-alerts".to_string())
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    let account_alerts = client
+        .read(
+            &AccountAlert::read_builder()
+                .system_alert_key(SystemAlertKey::BadAd)
+                .build(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(account_alerts, vec![bad_ad.clone()]);
+
+    client
+        .delete(&DeleteAccountAlert::from(&bad_ad))
+        .await
+        .unwrap();
+}
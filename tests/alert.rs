@@ -0,0 +1,156 @@
+extern crate beeswax;
+
+use beeswax::client::{
+    async_client::AsyncInMemoryClient, sync_client::SyncInMemoryClient, PollOptions,
+};
+use beeswax::resource::{
+    account_alert::SystemAlertKey,
+    alert::{AlertCursor, AlertIdFilter, ReadAlert},
+    Alert,
+};
+use serde_json::json;
+use std::time::Duration;
+
+fn alert(alert_id: u64, system_alert_key: SystemAlertKey) -> Alert {
+    Alert {
+        alert_id,
+        system_alert_key: Some(system_alert_key),
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_new_alerts_since_cursor() {
+    let client = AsyncInMemoryClient::new();
+    client.insert(alert(3, SystemAlertKey::BadAd));
+    client.insert(alert(1, SystemAlertKey::CampaignBudgetReached));
+
+    let mut cursor = AlertCursor::default();
+    let alerts = client.new_alerts(&mut cursor).await.unwrap();
+    let alert_ids: Vec<u64> = alerts.iter().map(|alert| alert.alert_id).collect();
+    assert_eq!(alert_ids, vec![1, 3]);
+    assert_eq!(cursor.last_alert_id, 3);
+
+    assert!(client.new_alerts(&mut cursor).await.unwrap().is_empty());
+
+    client.insert(alert(4, SystemAlertKey::CreativeRejected));
+    let alerts = client.new_alerts(&mut cursor).await.unwrap();
+    assert_eq!(alerts, vec![alert(4, SystemAlertKey::CreativeRejected)]);
+}
+
+#[tokio::test]
+async fn test_acknowledge_alert() {
+    let client = AsyncInMemoryClient::new();
+    let mut bad_ad = alert(1, SystemAlertKey::BadAd);
+
+    let unacknowledged = ReadAlert::builder().acknowledged(false).build();
+    assert!(unacknowledged == bad_ad);
+
+    client.acknowledge_alert(&mut bad_ad).await.unwrap();
+    assert_eq!(bad_ad.acknowledged, Some(true));
+    assert!(unacknowledged != bad_ad);
+}
+
+#[test]
+fn test_poll_alerts_sync() {
+    let client = SyncInMemoryClient::new();
+    let poll = PollOptions {
+        interval: Duration::from_millis(1),
+        max_attempts: 2,
+    };
+    let criteria = ReadAlert::builder()
+        .system_alert_key(SystemAlertKey::BadAd)
+        .build();
+    let mut cursor = AlertCursor::new(1, criteria);
+
+    client.insert(alert(1, SystemAlertKey::BadAd));
+    client.insert(alert(2, SystemAlertKey::ReportFailed));
+    assert!(cursor.poll_sync(&client, poll).unwrap().is_empty());
+
+    client.insert(alert(5, SystemAlertKey::BadAd));
+    let alerts = cursor.poll_sync(&client, poll).unwrap();
+    assert_eq!(alerts, vec![alert(5, SystemAlertKey::BadAd)]);
+    assert_eq!(cursor.last_alert_id, 5);
+}
+
+#[test]
+fn test_cursor_reads_after_last_alert() {
+    let criteria = ReadAlert::builder().acknowledged(false).build();
+    let cursor = AlertCursor::new(7, criteria);
+
+    let read_alert = serde_json::to_value(cursor.read_alert()).unwrap();
+
+    assert_eq!(read_alert["alert_id"], json!(">7"));
+    assert_eq!(read_alert["acknowledged"], json!(false));
+    assert_eq!(read_alert["sort_by"], json!("alert_id"));
+    assert_eq!(read_alert["order"], json!("asc"));
+}
+
+#[test]
+fn test_read_alert_id_filter() {
+    let read_alert = ReadAlert::builder()
+        .alert_id(AlertIdFilter::Equal(3))
+        .build();
+    assert_eq!(
+        serde_json::to_value(&read_alert).unwrap()["alert_id"],
+        json!(3)
+    );
+    assert!(read_alert == alert(3, SystemAlertKey::BadAd));
+    assert!(read_alert != alert(4, SystemAlertKey::BadAd));
+
+    // The cursor replaces the criteria's alert_id, so only one filter is ever sent
+    let cursor = AlertCursor::new(7, read_alert);
+    let read_alert = cursor.read_alert();
+    assert_eq!(read_alert.alert_id, Some(AlertIdFilter::GreaterThan(7)));
+    assert!(read_alert == alert(8, SystemAlertKey::BadAd));
+    assert!(read_alert != alert(3, SystemAlertKey::BadAd));
+}
+
+#[tokio::test]
+async fn test_new_alerts_paged() {
+    let client = AsyncInMemoryClient::new();
+    for alert_id in &[4, 2, 3, 1] {
+        client.insert(alert(*alert_id, SystemAlertKey::BadAd));
+    }
+    let mut cursor = AlertCursor::new(1, ReadAlert::builder().rows(2).build());
+
+    let first: Vec<u64> = client
+        .new_alerts(&mut cursor)
+        .await
+        .unwrap()
+        .iter()
+        .map(|alert| alert.alert_id)
+        .collect();
+    let second: Vec<u64> = client
+        .new_alerts(&mut cursor)
+        .await
+        .unwrap()
+        .iter()
+        .map(|alert| alert.alert_id)
+        .collect();
+
+    assert_eq!(first, vec![2, 3]);
+    assert_eq!(second, vec![4]);
+}
+
+#[tokio::test]
+async fn test_poll_alerts_async() {
+    let client = AsyncInMemoryClient::new();
+    let poll = PollOptions {
+        interval: Duration::from_millis(1),
+        max_attempts: 2,
+    };
+    let mut cursor = AlertCursor::new(1, ReadAlert::default());
+
+    client.insert(alert(1, SystemAlertKey::BadAd));
+    assert!(client
+        .poll_alerts(&mut cursor, poll)
+        .await
+        .unwrap()
+        .is_empty());
+
+    client.insert(alert(3, SystemAlertKey::ReportFailed));
+    let alerts = client.poll_alerts(&mut cursor, poll).await.unwrap();
+    assert_eq!(alerts, vec![alert(3, SystemAlertKey::ReportFailed)]);
+    assert_eq!(cursor.last_alert_id, 3);
+}
@@ -2,8 +2,10 @@ extern crate beeswax;
 
 mod helper;
 
+use beeswax::client::async_client::AsyncInMemoryClient;
 use beeswax::resource::Advertiser;
 use helper::{get_async_beeswax_client, random_string};
+use std::sync::Arc;
 
 #[tokio::test]
 async fn async_client() {
@@ -41,3 +43,30 @@ async fn async_client() {
         .await
         .expect("Could not delete")
 }
+
+#[tokio::test]
+async fn async_in_memory_client_spawn() {
+    let beeswax_client = Arc::new(AsyncInMemoryClient::new());
+    let spawned_client = beeswax_client.clone();
+    let create_advertiser = Advertiser::create_builder()
+        .advertiser_name("Advertiser Name")
+        .build();
+
+    let created_advertiser = tokio::spawn(async move {
+        spawned_client
+            .create(&create_advertiser)
+            .await
+            .expect("Could not create")
+    })
+    .await
+    .unwrap();
+
+    let read_advertiser = Advertiser::read_builder()
+        .advertiser_id(created_advertiser.advertiser_id)
+        .build();
+    let advertisers = beeswax_client
+        .read(&read_advertiser)
+        .await
+        .expect("Could not read");
+    assert_eq!(advertisers.len(), 1);
+}
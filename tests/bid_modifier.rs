@@ -0,0 +1,125 @@
+extern crate beeswax;
+
+use crate::helper::random_string;
+use beeswax::client::{async_client::AsyncInMemoryClient, sync_client::SyncInMemoryClient};
+use beeswax::resource::{
+    bid_modifier::BidModifierTerm, delivery_modifier::DeliveryModifierTerm, BidModifier, Create,
+    Delete, DeliveryModifier, Read, Resource,
+};
+
+mod helper;
+
+#[tokio::test]
+async fn test_bid_modifier() {
+    let beeswax_client = helper::get_async_beeswax_client().await.unwrap();
+
+    let create_bid_modifier = BidModifier::create_builder()
+        .bid_modifier_name(random_string("Bid modifier name"))
+        .terms(vec![
+            BidModifierTerm::new("domain", "example.com", 1.2),
+            BidModifierTerm::new("platform.device_type", 1, 0.8),
+        ])
+        .build();
+
+    let bid_modifier = beeswax_client.create(&create_bid_modifier).await.unwrap();
+
+    let read_bid_modifier = BidModifier::read_builder()
+        .bid_modifier_id(bid_modifier.bid_modifier_id)
+        .build();
+
+    let mut read_bid_modifier = beeswax_client
+        .read(&read_bid_modifier)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    assert_eq!(
+        read_bid_modifier.bid_modifier_id,
+        bid_modifier.bid_modifier_id
+    );
+    assert_eq!(
+        read_bid_modifier.bid_modifier_name,
+        bid_modifier.bid_modifier_name
+    );
+    assert_eq!(read_bid_modifier.terms, bid_modifier.terms);
+
+    read_bid_modifier.terms.pop();
+
+    beeswax_client.update(&read_bid_modifier).await.unwrap();
+
+    beeswax_client.delete(&read_bid_modifier).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_bid_modifier_in_memory() {
+    let beeswax_client = AsyncInMemoryClient::new();
+
+    let create_bid_modifier = BidModifier::create_builder()
+        .bid_modifier_name("Bid modifier name")
+        .terms(vec![
+            BidModifierTerm::new("domain", "example.com", 1.2),
+            BidModifierTerm::new("platform.device_type", 1, 0.8),
+        ])
+        .build();
+    let bid_modifier = beeswax_client.create(&create_bid_modifier).await.unwrap();
+
+    let read_bid_modifier = BidModifier::read_builder()
+        .bid_modifier_id(bid_modifier.bid_modifier_id)
+        .build();
+    let mut read = beeswax_client.read(&read_bid_modifier).await.unwrap();
+    assert_eq!(read, vec![bid_modifier.clone()]);
+
+    let mut updated = read.pop().unwrap();
+    updated.terms.pop();
+    beeswax_client.update(&updated).await.unwrap();
+    let read = beeswax_client.read(&read_bid_modifier).await.unwrap();
+    assert_eq!(
+        read[0].terms,
+        vec![BidModifierTerm::new("domain", "example.com", 1.2)]
+    );
+
+    let delete_bid_modifier = BidModifier::delete_builder()
+        .bid_modifier_id(bid_modifier.bid_modifier_id)
+        .build();
+    beeswax_client.delete(&delete_bid_modifier).await.unwrap();
+    assert!(beeswax_client
+        .read(&read_bid_modifier)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_delivery_modifier_in_memory() {
+    let beeswax_client = SyncInMemoryClient::new();
+
+    let delivery_modifier = DeliveryModifier::create_builder()
+        .delivery_modifier_name("Delivery modifier name")
+        .terms(vec![
+            DeliveryModifierTerm::new("domain", "example.com", 2.0),
+            DeliveryModifierTerm::new("platform.device_type", 1, 0.5),
+        ])
+        .build()
+        .create_sync(&beeswax_client)
+        .unwrap();
+
+    let read_delivery_modifier = DeliveryModifier::read_builder()
+        .delivery_modifier_id(delivery_modifier.delivery_modifier_id)
+        .build();
+    let mut read = read_delivery_modifier.read_sync(&beeswax_client).unwrap();
+    assert_eq!(read, vec![delivery_modifier.clone()]);
+
+    let mut updated = read.pop().unwrap();
+    updated.terms[0].weight = 3.0;
+    updated.update_sync(&beeswax_client).unwrap();
+    let read = read_delivery_modifier.read_sync(&beeswax_client).unwrap();
+    assert_eq!(read[0].terms[0].weight, 3.0);
+    assert_eq!(read[0].terms[1], delivery_modifier.terms[1]);
+
+    updated.delete_sync(&beeswax_client).unwrap();
+    assert!(read_delivery_modifier
+        .read_sync(&beeswax_client)
+        .unwrap()
+        .is_empty());
+}
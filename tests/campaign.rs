@@ -49,6 +49,7 @@ async fn test_campaign() {
         alternative_id: None,
         notes: None,
         active: false,
+        test_plan_id: None,
     };
 
     let campaign = beeswax_client.create(&create_campaign).await.unwrap();
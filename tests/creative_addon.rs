@@ -0,0 +1,66 @@
+extern crate beeswax;
+
+use beeswax::client::async_client::AsyncInMemoryClient;
+use beeswax::resource::{creative_addon::CreativeAddonType, Creative, CreativeAddon};
+
+#[test]
+fn test_attach_creative_addon() {
+    let creative_addon = CreativeAddon {
+        creative_addon_id: 5,
+        ..Default::default()
+    };
+    let mut creative = Creative::default();
+
+    assert!(!creative_addon.is_attached(&creative));
+    creative_addon.attach(&mut creative);
+    creative_addon.attach(&mut creative);
+    assert_eq!(creative.creative_addons, Some(vec![5]));
+    assert!(creative_addon.is_attached(&creative));
+
+    creative_addon.detach(&mut creative);
+    assert_eq!(creative.creative_addons, Some(vec![]));
+}
+
+#[test]
+fn test_creative_pixels() {
+    let mut creative = Creative::default();
+    let pixel = "https://pixel.example.com/p.gif";
+
+    assert!(!creative.has_pixel(pixel));
+    creative.add_pixel(pixel);
+    creative.add_pixel(pixel);
+    assert_eq!(creative.pixels, Some(vec![pixel.to_string()]));
+    assert!(creative.has_pixel(pixel));
+
+    creative.remove_pixel(pixel);
+    assert_eq!(creative.pixels, Some(vec![]));
+}
+
+#[tokio::test]
+async fn test_read_creative_addon() {
+    let client = AsyncInMemoryClient::new();
+
+    let create_creative_addon = CreativeAddon::create_builder()
+        .creative_addon_name("Viewability measurement")
+        .creative_addon_type(CreativeAddonType::Verification)
+        .creative_addon_content("<script src=\"https://verify.example.com/tag.js\"></script>")
+        .vendor("Example Verification".to_string())
+        .build();
+    let creative_addon = client.create(&create_creative_addon).await.unwrap();
+
+    let read_creative_addon = CreativeAddon::read_builder()
+        .creative_addon_type(CreativeAddonType::Verification)
+        .build();
+    let creative_addons = client.read(&read_creative_addon).await.unwrap();
+    assert_eq!(creative_addons, vec![creative_addon.clone()]);
+
+    let read_creative_addon = CreativeAddon::read_builder()
+        .creative_addon_type(CreativeAddonType::Pixel)
+        .build();
+    assert!(client.read(&read_creative_addon).await.unwrap().is_empty());
+
+    assert_eq!(
+        serde_json::to_value(&creative_addon).unwrap()["creative_addon_type"],
+        "verification"
+    );
+}
@@ -0,0 +1,106 @@
+extern crate beeswax;
+
+use beeswax::client::{
+    async_client::AsyncInMemoryClient,
+    sync_client::{SyncClient, SyncInMemoryClient},
+    PollOptions,
+};
+use beeswax::resource::{
+    common::ViewName,
+    creative_approval::{ApprovalStatus, CreativeApproval},
+    view_row::CreativeApprovalVendor,
+    Creative, View,
+};
+use serde_json::json;
+use std::time::Duration;
+
+fn creative() -> Creative {
+    Creative {
+        creative_id: 10,
+        ..Default::default()
+    }
+}
+
+fn poll() -> PollOptions {
+    PollOptions {
+        interval: Duration::from_millis(1),
+        max_attempts: 3,
+    }
+}
+
+#[tokio::test]
+async fn test_submit_for_audit() {
+    let client = AsyncInMemoryClient::new();
+
+    let creative_approvals = client
+        .submit_for_audit(&creative(), &["google", "appnexus"])
+        .await
+        .unwrap();
+    assert_eq!(creative_approvals.len(), 2);
+    assert!(creative_approvals
+        .iter()
+        .all(|creative_approval| creative_approval.is_pending()));
+
+    let error = client
+        .wait_for_approval(&creative(), poll())
+        .await
+        .unwrap_err();
+    assert!(error.to_string().contains("pending approval"));
+}
+
+#[test]
+fn test_wait_for_approval() {
+    let client = SyncInMemoryClient::new();
+    client.insert(CreativeApproval {
+        creative_approval_id: 1,
+        creative_id: 10,
+        vendor: "google".to_string(),
+        approval_status: ApprovalStatus::Approved,
+        ..Default::default()
+    });
+    client.insert(CreativeApproval {
+        creative_approval_id: 2,
+        creative_id: 10,
+        vendor: "appnexus".to_string(),
+        approval_status: ApprovalStatus::Rejected,
+        rejection_reasons: Some(vec!["Landing page does not load".to_string()]),
+        ..Default::default()
+    });
+
+    let creative_approvals = creative().wait_for_approval_sync(&client, poll()).unwrap();
+    let rejected: Vec<&CreativeApproval> = creative_approvals
+        .iter()
+        .filter(|creative_approval| creative_approval.approval_status == ApprovalStatus::Rejected)
+        .collect();
+    assert_eq!(rejected.len(), 1);
+    assert_eq!(rejected[0].vendor, "appnexus");
+    assert_eq!(
+        serde_json::to_value(rejected[0]).unwrap()["approval_status"],
+        "rejected"
+    );
+}
+
+#[test]
+fn test_wait_for_approval_not_submitted() {
+    let client = SyncInMemoryClient::new();
+
+    let error = creative()
+        .wait_for_approval_sync(&client, poll())
+        .unwrap_err();
+    assert!(error.to_string().contains("pending approval"));
+}
+
+#[test]
+fn test_creative_approval_vendors() {
+    let client = SyncInMemoryClient::new();
+    client.insert(View::new(
+        ViewName::CreativeApprovalVendors,
+        json!({"vendor": "google", "vendor_name": "Google", "requires_approval": true}),
+    ));
+
+    let vendors = client
+        .read_view::<CreativeApprovalVendor>(ViewName::CreativeApprovalVendors)
+        .unwrap();
+    assert_eq!(vendors[0].vendor, "google");
+    assert_eq!(vendors[0].requires_approval, Some(true));
+}
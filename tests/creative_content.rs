@@ -0,0 +1,98 @@
+extern crate beeswax;
+
+use beeswax::resource::{
+    common::CreativeType,
+    creative_content::{
+        BannerContent, CreativeContent, ProgressEvent, TrackingEvent, VastEvent, VideoContent,
+    },
+    Create, Creative,
+};
+use serde_json::json;
+
+#[test]
+fn test_creative_content() {
+    let mut create_creative = Creative::create_builder()
+        .advertiser_id(1)
+        .creative_name("Winter sale")
+        .build();
+
+    create_creative.set_content(CreativeContent::Video(VideoContent {
+        vast_tag_url: Some("https://example.com/vast.xml".to_string()),
+        duration: Some(30),
+        ..Default::default()
+    }));
+
+    assert_eq!(create_creative.creative_type, CreativeType::Video);
+    assert_eq!(
+        create_creative.creative_content,
+        Some(json!({ "VAST_TAG_URL": "https://example.com/vast.xml", "DURATION": 30 }))
+    );
+
+    let creative = create_creative.into_resource(1);
+    assert_eq!(
+        creative.content().unwrap(),
+        Some(CreativeContent::Video(VideoContent {
+            vast_tag_url: Some("https://example.com/vast.xml".to_string()),
+            duration: Some(30),
+            ..Default::default()
+        }))
+    );
+}
+
+#[test]
+fn test_creative_content_of_wrong_type() {
+    let create_creative = Creative::create_builder()
+        .advertiser_id(1)
+        .creative_name("Winter sale")
+        .creative_content(json!(["not", "an", "object"]))
+        .build();
+
+    assert!(create_creative.content().is_err());
+}
+
+#[test]
+fn test_banner_content_conversion() {
+    let banner = BannerContent {
+        tag: Some("<script src=\"https://example.com/ad.js\"></script>".to_string()),
+        ..Default::default()
+    };
+
+    let json = serde_json::Value::from(banner.clone());
+
+    assert_eq!(
+        json,
+        json!({ "TAG": "<script src=\"https://example.com/ad.js\"></script>" })
+    );
+    assert_eq!(std::convert::TryFrom::try_from(&json).ok(), Some(banner));
+}
+
+#[test]
+fn test_tracking_events() {
+    let mut create_creative = Creative::create_builder()
+        .advertiser_id(1)
+        .creative_name("Winter sale")
+        .build();
+
+    assert_eq!(create_creative.tracking_events().unwrap(), vec![]);
+
+    create_creative.set_tracking_events(vec![TrackingEvent {
+        event: VastEvent::FirstQuartile,
+        url: "https://example.com/q1".to_string(),
+    }]);
+    create_creative.set_progress_tracking_events(vec![ProgressEvent {
+        offset: "00:00:05".to_string(),
+        url: "https://example.com/5s".to_string(),
+    }]);
+
+    assert_eq!(
+        create_creative.events,
+        Some(json!([{ "event": "firstQuartile", "url": "https://example.com/q1" }]))
+    );
+    assert_eq!(
+        create_creative.progress_tracking_events().unwrap(),
+        vec![ProgressEvent {
+            offset: "00:00:05".to_string(),
+            url: "https://example.com/5s".to_string(),
+        }]
+    );
+}
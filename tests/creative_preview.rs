@@ -0,0 +1,128 @@
+extern crate beeswax;
+
+use beeswax::client::{
+    async_client::AsyncInMemoryClient,
+    sync_client::{SyncClient, SyncInMemoryClient},
+};
+use beeswax::resource::{
+    creative_preview::{CreateCreativePreviewToken, CreativePreview},
+    Create, Creative, CreativeLineItem, Read,
+};
+
+const PREVIEW_PAGE: &str = "https://buzz.example.com/creative_preview";
+
+fn creative(creative_id: u64, preview_token: Option<&str>) -> Creative {
+    Creative {
+        creative_id,
+        creative_name: format!("Creative {}", creative_id),
+        preview_token: preview_token.map(str::to_string),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_preview_url() {
+    assert_eq!(creative(10, None).preview_url(PREVIEW_PAGE), None);
+    assert_eq!(
+        creative(10, Some("abc123"))
+            .preview_url(PREVIEW_PAGE)
+            .unwrap(),
+        "https://buzz.example.com/creative_preview?creative_id=10&preview_token=abc123"
+    );
+    assert_eq!(
+        creative(10, Some("a+b/c=&d"))
+            .preview_url(PREVIEW_PAGE)
+            .unwrap(),
+        "https://buzz.example.com/creative_preview?creative_id=10&preview_token=a%2Bb%2Fc%3D%26d"
+    );
+}
+
+#[tokio::test]
+async fn test_refresh_preview_token() {
+    let client = AsyncInMemoryClient::new();
+    let mut creative = creative(10, Some("abc123"));
+    client.insert(creative.clone());
+
+    let preview_token = client.refresh_preview_token(&mut creative).await.unwrap();
+    assert_ne!(preview_token, "abc123");
+    assert_eq!(creative.preview_token, Some(preview_token.clone()));
+
+    let read_creative = Creative::read_builder().creative_id(10).build();
+    let creatives = client.read(&read_creative).await.unwrap();
+    assert_eq!(creatives[0].preview_token, Some(preview_token));
+}
+
+#[test]
+fn test_created_token_is_not_made_up() {
+    let create_creative_preview_token = CreateCreativePreviewToken { creative_id: 10 };
+    let creative_preview_token = create_creative_preview_token.into_resource(1);
+    assert_eq!(creative_preview_token.preview_token, "");
+}
+
+#[test]
+fn test_refresh_preview_token_sync() {
+    let client = SyncInMemoryClient::new();
+    let mut creative = creative(10, None);
+    client.insert(creative.clone());
+
+    let preview_token = creative.refresh_preview_token_sync(&client).unwrap();
+    assert_eq!(creative.preview_token, Some(preview_token.clone()));
+
+    let creatives = Creative::read_builder()
+        .creative_id(10)
+        .build()
+        .read_sync(&client)
+        .unwrap();
+    assert_eq!(creatives[0].preview_token, Some(preview_token));
+}
+
+#[test]
+fn test_line_item_previews() {
+    let client = SyncInMemoryClient::new();
+    client.insert(creative(10, Some("abc123")));
+    client.insert(creative(11, None));
+    client.insert(creative(12, Some("def456")));
+    for (creative_id, line_item_id) in &[(10, 20), (11, 20), (12, 21)] {
+        let create_creative_line_item = CreativeLineItem::create_builder()
+            .creative_id(*creative_id)
+            .line_item_id(*line_item_id)
+            .build();
+        client.create(&create_creative_line_item).unwrap();
+    }
+
+    let mut creative_previews =
+        CreativePreview::for_line_item_sync(&client, 20, PREVIEW_PAGE).unwrap();
+    creative_previews.sort_by_key(|creative_preview| creative_preview.creative_id);
+    assert_eq!(creative_previews.len(), 2);
+    assert!(creative_previews[0]
+        .preview_url
+        .ends_with("&preview_token=abc123"));
+    assert!(creative_previews[1]
+        .preview_url
+        .starts_with("https://buzz.example.com/creative_preview?creative_id=11&preview_token="));
+}
+
+#[test]
+fn test_line_item_previews_reuse_refreshed_token() {
+    let client = SyncInMemoryClient::new();
+    client.insert(creative(11, None));
+    let create_creative_line_item = CreativeLineItem::create_builder()
+        .creative_id(11)
+        .line_item_id(20)
+        .build();
+    client.create(&create_creative_line_item).unwrap();
+
+    let first = CreativePreview::for_line_item_sync(&client, 20, PREVIEW_PAGE).unwrap();
+    let second = CreativePreview::for_line_item_sync(&client, 20, PREVIEW_PAGE).unwrap();
+    assert_eq!(first.len(), 1);
+    assert_eq!(first, second);
+
+    let creatives = Creative::read_builder()
+        .creative_id(11)
+        .build()
+        .read_sync(&client)
+        .unwrap();
+    assert!(first[0]
+        .preview_url
+        .ends_with(creatives[0].preview_token.as_ref().unwrap()));
+}
@@ -0,0 +1,107 @@
+extern crate beeswax;
+
+use beeswax::resource::{
+    creative_content::{CreativeContent, NativeContent},
+    creative_template::{CreativeTemplateField, TemplateFieldType},
+    Creative, CreativeTemplate,
+};
+use serde_json::json;
+
+fn native_template() -> CreativeTemplate {
+    let field = |field_name: &str, field_type, required| CreativeTemplateField {
+        field_name: field_name.to_string(),
+        field_type,
+        required,
+        default_value: None,
+        max_length: None,
+    };
+    CreativeTemplate {
+        creative_template_id: 7,
+        fields: Some(vec![
+            field("title", TemplateFieldType::String, true),
+            field("image", TemplateFieldType::Asset, true),
+            field("click_url", TemplateFieldType::Url, true),
+            field("rating", TemplateFieldType::Number, false),
+        ]),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_valid_creative_content() {
+    let template = native_template();
+    let content = json!({
+        "title": "Winter sale",
+        "image": 123,
+        "click_url": "https://example.com",
+    });
+
+    assert!(template.validate_content(&content).is_ok());
+}
+
+#[test]
+fn test_invalid_creative_content() {
+    let template = native_template();
+    let content = json!({
+        "title": 12,
+        "click_url": "example.com",
+        "sponsor": "Example",
+    });
+
+    let error = template.validate_content(&content).unwrap_err();
+
+    assert_eq!(
+        error.problems,
+        vec![
+            "field title should be of type String",
+            "missing required field image",
+            "field click_url should be of type Url",
+            "unknown field sponsor",
+        ]
+    );
+}
+
+#[test]
+fn test_creative_uses_other_template() {
+    let template = native_template();
+    let create_creative = Creative::create_builder()
+        .advertiser_id(1)
+        .creative_name("Winter sale")
+        .creative_template_id(8u64)
+        .creative_content(json!({}))
+        .build();
+
+    assert!(create_creative.validate_content(&template).is_err());
+}
+
+#[test]
+fn test_typed_content_matches_template() {
+    let field = |field_name: &str, field_type, required| CreativeTemplateField {
+        field_name: field_name.to_string(),
+        field_type,
+        required,
+        default_value: None,
+        max_length: None,
+    };
+    let template = CreativeTemplate {
+        creative_template_id: 8,
+        fields: Some(vec![
+            field("TITLE", TemplateFieldType::String, true),
+            field("IMAGE", TemplateFieldType::Asset, true),
+            field("CALL_TO_ACTION", TemplateFieldType::String, false),
+        ]),
+        ..Default::default()
+    };
+    let mut creative = Creative {
+        creative_template_id: 8,
+        ..Default::default()
+    };
+    creative.set_content(CreativeContent::Native(NativeContent {
+        title: Some("Winter sale".to_string()),
+        image: Some(123),
+        call_to_action: Some("Shop now".to_string()),
+        ..Default::default()
+    }));
+
+    assert_eq!(creative.validate_content(&template), Ok(()));
+}
@@ -0,0 +1,83 @@
+extern crate beeswax;
+
+use beeswax::client::async_client::AsyncInMemoryClient;
+use beeswax::resource::{common::Currency, Deal, DealList};
+
+#[tokio::test]
+async fn test_deal_crud() {
+    let client = AsyncInMemoryClient::new();
+
+    let deal = client
+        .create(
+            &Deal::create_builder()
+                .deal_name("Premium news PMP")
+                .deal_identifier("PMP-1234")
+                .inventory_source("google")
+                .floor_price(2.5)
+                .currency(Currency::UnitedStatesDollar)
+                .build(),
+        )
+        .await
+        .unwrap();
+    client
+        .create(
+            &Deal::create_builder()
+                .deal_name("Sports PMP")
+                .deal_identifier("PMP-5678")
+                .inventory_source("rubicon")
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    let deals = client
+        .read(
+            &Deal::read_builder()
+                .inventory_source("google".to_string())
+                .build(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(deals, vec![deal.clone()]);
+    assert_eq!(deal.floor_price, Some(2.5));
+
+    client.update(&deal).await.unwrap();
+    client.delete(&deal).await.unwrap();
+}
+
+#[test]
+fn test_deal_list_membership() {
+    let first = Deal {
+        deal_id: 1,
+        ..Default::default()
+    };
+    let second = Deal {
+        deal_id: 2,
+        ..Default::default()
+    };
+    let mut deal_list = DealList {
+        deal_ids: vec![1],
+        ..Default::default()
+    };
+
+    deal_list.add_deal(&first);
+    deal_list.add_deal(&second);
+    assert_eq!(deal_list.deal_ids, vec![1, 2]);
+    assert!(deal_list.contains(&second));
+
+    deal_list.remove_deal(&first);
+    assert_eq!(deal_list.deal_ids, vec![2]);
+    assert!(!deal_list.contains(&first));
+}
+
+#[test]
+fn test_deal_json() {
+    let deal: Deal = serde_json::from_str(
+        r#"{"deal_id": 7, "deal_name": "PMP", "deal_identifier": "abc",
+            "inventory_source": "google", "floor_price": 1.25, "currency": "EUR"}"#,
+    )
+    .unwrap();
+
+    assert_eq!(deal.deal_identifier, "abc");
+    assert_eq!(deal.currency, Some(Currency::Euro));
+}
@@ -0,0 +1,136 @@
+extern crate beeswax;
+
+use beeswax::client::async_client::AsyncInMemoryClient;
+use beeswax::resource::{
+    app_list::UploadAppList, domain_list::UploadDomainList, inventory_list::UploadInventoryList,
+    AppList, DomainList, InventoryList,
+};
+use serde_json::json;
+use std::fs;
+
+#[test]
+fn test_bulk_edit_domains() {
+    let mut domain_list = DomainList::default();
+
+    domain_list.add_domains(vec!["example.com", "https://Bad.example/", "example.com"]);
+    assert_eq!(domain_list.domains, vec!["example.com", "bad.example"]);
+    assert!(domain_list.contains("BAD.example"));
+
+    domain_list.remove_domains(["http://example.com"]);
+    assert_eq!(domain_list.domains, vec!["bad.example"]);
+}
+
+#[test]
+fn test_bulk_edit_apps_and_placements() {
+    let mut app_list = AppList::default();
+    app_list.add_apps(vec![" com.example.game ", "com.example.news"]);
+    app_list.remove_apps(vec!["com.example.news"]);
+    assert_eq!(app_list.apps, vec!["com.example.game"]);
+
+    let mut inventory_list = InventoryList::default();
+    inventory_list.add_placements(vec!["12345/top_banner", "12345/top_banner"]);
+    assert_eq!(inventory_list.placements, vec!["12345/top_banner"]);
+    assert!(inventory_list.contains("12345/top_banner"));
+}
+
+#[test]
+fn test_upload_file_content() {
+    let upload = UploadDomainList::from_domains(3, vec!["example.com", "bad.example"]);
+    assert_eq!(upload.file_name, "domain_list_3.txt");
+    assert_eq!(upload.content, b"example.com\nbad.example\n".to_vec());
+
+    let upload = UploadAppList::builder()
+        .app_list_id(4)
+        .file_name("apps.txt")
+        .content(b"# kids apps\ncom.example.game\n\n com.example.news\r\n".to_vec())
+        .build();
+    assert_eq!(
+        upload.apps().unwrap(),
+        vec!["com.example.game", "com.example.news"]
+    );
+}
+
+#[test]
+fn test_upload_from_path() {
+    let path = std::env::temp_dir().join("beeswax_inventory_list_test.txt");
+    fs::write(&path, "12345/top_banner\n67890/sidebar\n").unwrap();
+
+    let upload = UploadInventoryList::from_path(5, &path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(upload.file_name, "beeswax_inventory_list_test.txt");
+    assert_eq!(
+        upload.placements().unwrap(),
+        vec!["12345/top_banner", "67890/sidebar"]
+    );
+}
+
+#[tokio::test]
+async fn test_domain_list_crud() {
+    let client = AsyncInMemoryClient::new();
+
+    let domain_list = client
+        .create(
+            &DomainList::create_builder()
+                .domain_list_name("Brand safety blocks")
+                .domains(vec!["Example.com".to_string()])
+                .build(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(domain_list.domains, vec!["example.com"]);
+
+    let domain_lists = client
+        .read(
+            &DomainList::read_builder()
+                .domain_list_name("Brand safety blocks".to_string())
+                .build(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(domain_lists, vec![domain_list.clone()]);
+
+    let upload = UploadDomainList::from_domains(domain_list.domain_list_id, vec!["bad.example"]);
+    client.upload(&upload).await.unwrap();
+    let read_domain_list = DomainList::read_builder()
+        .domain_list_id(domain_list.domain_list_id)
+        .build();
+    let uploaded = client.read(&read_domain_list).await.unwrap();
+    assert_eq!(uploaded[0].domains, vec!["bad.example"]);
+
+    let malformed = UploadDomainList::builder()
+        .domain_list_id(domain_list.domain_list_id)
+        .file_name("blocks.txt")
+        .content(vec![0xff, 0xfe])
+        .build();
+    assert!(client.upload(&malformed).await.is_err());
+    let uploaded = client.read(&read_domain_list).await.unwrap();
+    assert_eq!(uploaded[0].domains, vec!["bad.example"]);
+
+    client.delete(&uploaded[0]).await.unwrap();
+    assert!(client.read(&read_domain_list).await.unwrap().is_empty());
+}
+
+#[test]
+fn test_create_normalizes_items() {
+    let create_domain_list = DomainList::create_builder()
+        .domain_list_name("Brand safety blocks")
+        .domains(vec![
+            "https://Example.com/".to_string(),
+            "example.com".to_string(),
+        ])
+        .build();
+    let create_app_list = AppList::create_builder()
+        .app_list_name("Kids apps")
+        .apps(vec![" com.example.game ".to_string()])
+        .build();
+
+    assert_eq!(
+        serde_json::to_value(&create_domain_list).unwrap()["domains"],
+        json!(["example.com"])
+    );
+    assert_eq!(
+        serde_json::to_value(&create_app_list).unwrap()["apps"],
+        json!(["com.example.game"])
+    );
+}
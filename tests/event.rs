@@ -0,0 +1,95 @@
+extern crate beeswax;
+
+use beeswax::client::async_client::AsyncInMemoryClient;
+use beeswax::resource::{
+    advertiser::CreateAdvertiser,
+    common::{ConversionMethod, EventTagType},
+    event::CreateEvent,
+    event_tag::ReadEventTag,
+    Create, Event, EventTag,
+};
+
+fn advertiser(conversion_method_id: ConversionMethod) -> beeswax::resource::Advertiser {
+    CreateAdvertiser {
+        conversion_method_id,
+        ..Default::default()
+    }
+    .into_resource(1)
+}
+
+fn event(click_window: Option<u64>, view_window: Option<u64>) -> Event {
+    CreateEvent {
+        event_name: "Purchase".to_string(),
+        advertiser_id: 1,
+        click_window,
+        view_window,
+        ..Default::default()
+    }
+    .into_resource(2)
+}
+
+#[test]
+fn test_last_click_attribution() {
+    let attribution = event(Some(60), None)
+        .attribution(&advertiser(ConversionMethod::LastClick))
+        .unwrap();
+
+    assert_eq!(attribution.conversion_method, ConversionMethod::LastClick);
+    assert_eq!(attribution.click_window, Some(60));
+}
+
+#[test]
+fn test_last_click_does_not_count_views() {
+    let event = event(Some(60), Some(30));
+
+    assert!(event
+        .attribution(&advertiser(ConversionMethod::LastClick))
+        .is_err());
+}
+
+#[test]
+fn test_attribution_other_advertiser() {
+    let mut other = advertiser(ConversionMethod::LastClick);
+    other.advertiser_id = 5;
+
+    assert!(event(None, None).attribution(&other).is_err());
+}
+
+#[tokio::test]
+async fn test_read_event_tags() {
+    let beeswax_client = AsyncInMemoryClient::new();
+    let event = beeswax_client
+        .create(
+            &Event::create_builder()
+                .event_name("Sign up")
+                .advertiser_id(1)
+                .build(),
+        )
+        .await
+        .unwrap();
+    for tag_type in &[EventTagType::ImagePixel, EventTagType::JavaScript] {
+        beeswax_client.insert(EventTag {
+            event_id: event.event_id,
+            tag_type: *tag_type,
+            tag: "<img>".to_string(),
+            ..Default::default()
+        });
+    }
+
+    let all_tags = beeswax_client
+        .read(&ReadEventTag::from(&event))
+        .await
+        .unwrap();
+    let script_tags = beeswax_client
+        .read(
+            &EventTag::read_builder()
+                .event_id(event.event_id)
+                .tag_type(EventTagType::JavaScript)
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(all_tags.len(), 2);
+    assert_eq!(script_tags.len(), 1);
+}
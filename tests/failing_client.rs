@@ -0,0 +1,71 @@
+use beeswax::client::sync_client::{SyncClient, SyncInMemoryClient};
+use beeswax::resource::{Create, Delete, Read, Resource, Upload};
+use beeswax::Result;
+use std::cell::Cell;
+use std::io::{Read as IoRead, Write};
+
+/// An in-memory client where a single create fails once a number of others have succeeded
+pub struct FailingClient {
+    inner: SyncInMemoryClient,
+    creates_before_failure: Cell<Option<usize>>,
+}
+
+impl FailingClient {
+    pub fn new() -> FailingClient {
+        FailingClient {
+            inner: SyncInMemoryClient::new(),
+            creates_before_failure: Cell::new(None),
+        }
+    }
+
+    /// Fail the next create after the given number have succeeded
+    pub fn fail_create_after(&self, creates: usize) {
+        self.creates_before_failure.set(Some(creates));
+    }
+}
+
+impl Default for FailingClient {
+    fn default() -> FailingClient {
+        FailingClient::new()
+    }
+}
+
+impl SyncClient for FailingClient {
+    fn read<R: Resource, F: Read<R>>(&self, criteria: &F) -> Result<Vec<R>> {
+        self.inner.read(criteria)
+    }
+
+    fn create<R: Resource, C: Create<R>>(&self, create: &C) -> Result<R> {
+        match self.creates_before_failure.get() {
+            Some(0) => {
+                self.creates_before_failure.set(None);
+                Err("create failed".into())
+            }
+            creates => {
+                self.creates_before_failure
+                    .set(creates.map(|creates| creates - 1));
+                self.inner.create(create)
+            }
+        }
+    }
+
+    fn update<'a, R: Resource>(&self, resource: &'a R) -> Result<&'a R> {
+        self.inner.update(resource)
+    }
+
+    fn delete<R: Resource, D: Delete<R>>(&self, delete: &D) -> Result<()> {
+        self.inner.delete(delete)
+    }
+
+    fn upload<R: Resource, U: Upload<R>>(&self, upload: &U) -> Result<()> {
+        self.inner.upload(upload)
+    }
+
+    fn download<R: Resource, W: Write>(&self, id: u64, writer: &mut W) -> Result<u64> {
+        self.inner.download::<R, W>(id, writer)
+    }
+
+    fn download_reader<R: Resource>(&self, id: u64) -> Result<Box<dyn IoRead + Send>> {
+        self.inner.download_reader::<R>(id)
+    }
+}
@@ -0,0 +1,162 @@
+extern crate beeswax;
+
+use crate::failing_client::FailingClient;
+use beeswax::client::{async_client::AsyncInMemoryClient, sync_client::SyncClient};
+use beeswax::resource::{
+    line_item::CreateLineItem, line_item_flight::CreateLineItemFlight, Create, LineItem,
+    LineItemFlight,
+};
+
+mod failing_client;
+
+fn line_item() -> LineItem {
+    CreateLineItem {
+        start_date: "2020-01-01 00:00:00".to_string(),
+        end_date: Some("2020-03-31 23:59:59".to_string()),
+        ..Default::default()
+    }
+    .into_resource(1)
+}
+
+fn flight(start_date: &str, end_date: &str) -> CreateLineItemFlight {
+    LineItemFlight::create_builder()
+        .line_item_id(1)
+        .start_date(start_date)
+        .end_date(end_date)
+        .budget(100.0)
+        .build()
+}
+
+#[test]
+fn test_valid_flights() {
+    let flights = vec![
+        flight("2020-02-01 00:00:00", "2020-02-29 23:59:59"),
+        flight("2020-01-01 00:00:00", "2020-01-31 23:59:59"),
+    ];
+
+    assert!(line_item().validate_flights(&flights).is_ok());
+}
+
+#[test]
+fn test_valid_flights_without_times() {
+    let flights = vec![
+        flight("2020-01-01", "2020-01-31"),
+        flight("2020-02-01 00:00:00", "2020-03-31"),
+    ];
+
+    assert!(line_item().validate_flights(&flights).is_ok());
+}
+
+#[test]
+fn test_overlapping_flights_without_times() {
+    let flights = vec![
+        flight("2020-01-01", "2020-01-31"),
+        flight("2020-01-31 12:00:00", "2020-02-29"),
+    ];
+
+    assert!(line_item().validate_flights(&flights).is_err());
+}
+
+#[test]
+fn test_invalid_flight_date() {
+    let flights = vec![flight("January 2020", "2020-01-31")];
+
+    assert!(line_item().validate_flights(&flights).is_err());
+}
+
+#[test]
+fn test_overlapping_flights() {
+    let flights = vec![
+        flight("2020-01-01 00:00:00", "2020-02-15 23:59:59"),
+        flight("2020-02-01 00:00:00", "2020-02-29 23:59:59"),
+    ];
+
+    assert!(line_item().validate_flights(&flights).is_err());
+}
+
+#[test]
+fn test_flights_outside_line_item() {
+    let flights = vec![flight("2020-03-01 00:00:00", "2020-04-30 23:59:59")];
+
+    assert!(line_item().validate_flights(&flights).is_err());
+}
+
+#[tokio::test]
+async fn test_replace_line_item_flights() {
+    let beeswax_client = AsyncInMemoryClient::new();
+    let flights = vec![
+        flight("2020-01-01 00:00:00", "2020-01-31 23:59:59"),
+        flight("2020-02-01 00:00:00", "2020-02-29 23:59:59"),
+    ];
+
+    let created = beeswax_client
+        .replace_line_item_flights(&line_item(), &flights)
+        .await
+        .unwrap();
+
+    assert_eq!(created.len(), 2);
+    assert_eq!(created[0].start_date, "2020-01-01 00:00:00");
+    assert_eq!(created[1].budget, Some(100.0));
+}
+
+#[tokio::test]
+async fn test_replace_line_item_flights_twice() {
+    let beeswax_client = AsyncInMemoryClient::new();
+    let line_item = line_item();
+    let first = vec![flight("2020-01-01 00:00:00", "2020-01-31 23:59:59")];
+    let second = vec![
+        flight("2020-02-01 00:00:00", "2020-02-29 23:59:59"),
+        flight("2020-03-01 00:00:00", "2020-03-31 23:59:59"),
+    ];
+
+    beeswax_client
+        .replace_line_item_flights(&line_item, &first)
+        .await
+        .unwrap();
+    let created = beeswax_client
+        .replace_line_item_flights(&line_item, &second)
+        .await
+        .unwrap();
+    let mut read = beeswax_client
+        .read(&LineItemFlight::read_builder().line_item_id(1).build())
+        .await
+        .unwrap();
+    read.sort_by(|a, b| a.start_date.cmp(&b.start_date));
+
+    assert_eq!(read, created);
+    assert_eq!(read.len(), 2);
+    assert_eq!(read[0].start_date, "2020-02-01 00:00:00");
+    assert_eq!(read[1].start_date, "2020-03-01 00:00:00");
+}
+
+#[test]
+fn test_replace_flights_rolls_back_failed_create() {
+    let line_item = line_item();
+    let beeswax_client = FailingClient::new();
+    let existing = vec![
+        flight("2020-01-01 00:00:00", "2020-01-31 23:59:59"),
+        flight("2020-02-01 00:00:00", "2020-02-29 23:59:59"),
+    ];
+    line_item
+        .replace_flights_sync(&beeswax_client, &existing)
+        .unwrap();
+
+    // The second new flight fails so the first is removed again and the existing flights recreated
+    beeswax_client.fail_create_after(1);
+    let replacement = vec![
+        flight("2020-01-01 00:00:00", "2020-02-15 23:59:59"),
+        flight("2020-02-16 00:00:00", "2020-03-31 23:59:59"),
+    ];
+    let result = line_item.replace_flights_sync(&beeswax_client, &replacement);
+    let mut read = beeswax_client
+        .read(&LineItemFlight::read_builder().line_item_id(1).build())
+        .unwrap();
+    read.sort_by(|a, b| a.start_date.cmp(&b.start_date));
+
+    assert!(result.is_err());
+    assert_eq!(read.len(), 2);
+    assert_eq!(read[0].start_date, "2020-01-01 00:00:00");
+    assert_eq!(read[0].end_date, "2020-01-31 23:59:59");
+    assert_eq!(read[1].start_date, "2020-02-01 00:00:00");
+    assert_eq!(read[1].end_date, "2020-02-29 23:59:59");
+}
@@ -0,0 +1,90 @@
+extern crate beeswax;
+
+use beeswax::client::{async_client::AsyncInMemoryClient, sync_client::SyncInMemoryClient};
+use beeswax::resource::{common::ViewName, lookup_cache::LookupCache, View};
+use serde_json::json;
+use std::time::Duration;
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+fn client_with_lookups() -> SyncInMemoryClient {
+    let beeswax_client = SyncInMemoryClient::new();
+    beeswax_client.insert(View::new(
+        ViewName::Countries,
+        json!({"country_code": "USA", "country_code_2": "US", "country_name": "United States"}),
+    ));
+    beeswax_client.insert(View::new(
+        ViewName::CreativeSizes,
+        json!({"width": 300, "height": 250}),
+    ));
+    beeswax_client.insert(View::new(
+        ViewName::Currency,
+        json!({"currency_code": "USD"}),
+    ));
+    beeswax_client.insert(View::new(
+        ViewName::MimeTypes,
+        json!({"mime_type": "image/png"}),
+    ));
+    beeswax_client
+}
+
+#[test]
+fn test_lookups() {
+    let mut lookup_cache = LookupCache::new(DAY);
+    assert!(lookup_cache.is_stale());
+
+    lookup_cache
+        .refresh_if_stale_sync(&client_with_lookups())
+        .unwrap();
+
+    assert!(!lookup_cache.is_stale());
+    assert_eq!(
+        lookup_cache.country_by_code("us").unwrap().country_code,
+        "USA"
+    );
+    assert!(lookup_cache.country_by_code("GBR").is_none());
+    assert!(lookup_cache.currency_by_code("USD").is_some());
+    assert!(lookup_cache.is_valid_creative_size(300, 250));
+    assert!(!lookup_cache.is_valid_creative_size(250, 300));
+    assert!(lookup_cache.is_valid_mime_type("image/png"));
+}
+
+#[test]
+fn test_zero_ttl_is_always_stale() {
+    let mut lookup_cache = LookupCache::new(Duration::from_secs(0));
+
+    lookup_cache.refresh_sync(&client_with_lookups()).unwrap();
+
+    assert!(lookup_cache.is_stale());
+}
+
+#[test]
+fn test_save_and_load() {
+    let path = std::env::temp_dir().join(format!("beeswax-lookups-{}.json", std::process::id()));
+    let mut lookup_cache = LookupCache::new(DAY);
+    assert!(lookup_cache.save(&path).is_err());
+
+    lookup_cache.refresh_sync(&client_with_lookups()).unwrap();
+    lookup_cache.save(&path).unwrap();
+    let loaded = LookupCache::load(&path, DAY).unwrap();
+    let expired = LookupCache::load(&path, Duration::from_secs(0)).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded.tables(), lookup_cache.tables());
+    assert!(!loaded.is_stale());
+    assert!(expired.is_stale());
+}
+
+#[tokio::test]
+async fn test_async_refresh() {
+    let beeswax_client = AsyncInMemoryClient::new();
+    let mut lookup_cache = LookupCache::new(DAY);
+
+    beeswax_client
+        .refresh_lookups_if_stale(&mut lookup_cache)
+        .await
+        .unwrap();
+
+    assert!(!lookup_cache.is_stale());
+    assert!(!lookup_cache.is_valid_creative_size(300, 250));
+}
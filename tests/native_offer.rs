@@ -0,0 +1,151 @@
+extern crate beeswax;
+
+use crate::failing_client::FailingClient;
+use beeswax::client::{
+    async_client::AsyncInMemoryClient,
+    sync_client::{SyncClient, SyncInMemoryClient},
+};
+use beeswax::resource::{
+    common::CreativeType,
+    native_offer::{NativeImageType, NativeOfferImage},
+    Creative, NativeOffer,
+};
+
+mod failing_client;
+
+fn image(image_type: NativeImageType, creative_asset_id: u64) -> NativeOfferImage {
+    NativeOfferImage {
+        image_type,
+        creative_asset_id,
+        width: None,
+        height: None,
+    }
+}
+
+#[test]
+fn test_native_offer_images() {
+    let native_offer = NativeOffer {
+        images: vec![
+            image(NativeImageType::Main, 2),
+            image(NativeImageType::Icon, 3),
+        ],
+        ..Default::default()
+    };
+
+    assert_eq!(
+        native_offer
+            .image(NativeImageType::Icon)
+            .unwrap()
+            .creative_asset_id,
+        3
+    );
+    assert_eq!(
+        serde_json::to_value(&native_offer.images[0]).unwrap()["image_type"],
+        "main"
+    );
+}
+
+#[tokio::test]
+async fn test_create_native_creative() {
+    let client = AsyncInMemoryClient::new();
+
+    let create_native_offer = NativeOffer::create_builder()
+        .advertiser_id(4)
+        .native_offer_name("Winter sale native")
+        .title("Half price winter coats")
+        .call_to_action("Shop now".to_string())
+        .images(vec![image(NativeImageType::Main, 2)])
+        .build();
+    let create_creative = Creative::create_builder()
+        .advertiser_id(4)
+        .creative_name("Winter sale native")
+        .build();
+
+    let creative = client
+        .create_native_creative(&create_native_offer, &create_creative)
+        .await
+        .unwrap();
+    assert_eq!(creative.creative_type, CreativeType::Native);
+    assert_eq!(creative.advertiser_id, 4);
+
+    let native_offers = client
+        .read(
+            &NativeOffer::read_builder()
+                .native_offer_id(creative.native_offer.unwrap())
+                .build(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(native_offers.len(), 1);
+    assert_eq!(native_offers[0].title, "Half price winter coats");
+}
+
+#[test]
+fn test_create_native_creative_sync() {
+    let client = SyncInMemoryClient::new();
+
+    let create_native_offer = NativeOffer::create_builder()
+        .advertiser_id(1)
+        .native_offer_name("Winter sale native")
+        .title("Half price winter coats")
+        .build();
+    let create_creative = Creative::create_builder()
+        .advertiser_id(1)
+        .creative_name("Winter sale native")
+        .build();
+
+    let creative = create_native_offer
+        .create_native_creative_sync(&client, &create_creative)
+        .unwrap();
+    assert_eq!(creative.creative_type, CreativeType::Native);
+    assert!(creative.native_offer.is_some());
+}
+
+#[tokio::test]
+async fn test_create_native_creative_other_advertiser() {
+    let client = AsyncInMemoryClient::new();
+
+    let create_native_offer = NativeOffer::create_builder()
+        .advertiser_id(4)
+        .native_offer_name("Winter sale native")
+        .title("Half price winter coats")
+        .build();
+    let create_creative = Creative::create_builder()
+        .advertiser_id(1)
+        .creative_name("Winter sale native")
+        .build();
+
+    assert!(client
+        .create_native_creative(&create_native_offer, &create_creative)
+        .await
+        .is_err());
+    assert!(client
+        .read(&NativeOffer::read_builder().advertiser_id(4).build())
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_create_native_creative_deletes_offer_on_failure() {
+    let client = FailingClient::new();
+    client.fail_create_after(1);
+
+    let create_native_offer = NativeOffer::create_builder()
+        .advertiser_id(1)
+        .native_offer_name("Winter sale native")
+        .title("Half price winter coats")
+        .build();
+    let create_creative = Creative::create_builder()
+        .advertiser_id(1)
+        .creative_name("Winter sale native")
+        .build();
+
+    assert!(create_native_offer
+        .create_native_creative_sync(&client, &create_creative)
+        .is_err());
+    assert!(client
+        .read(&NativeOffer::read_builder().advertiser_id(1).build())
+        .unwrap()
+        .is_empty());
+}
@@ -0,0 +1,90 @@
+extern crate beeswax;
+
+use beeswax::client::async_client::AsyncInMemoryClient;
+use beeswax::resource::{
+    campaign_preset::ReadCampaignPreset,
+    common::{BudgetType, Currency, FrequencyCap, WeightingMethod},
+    line_item_preset::ReadLineItemPreset,
+    Advertiser, Campaign, CampaignPreset, LineItem, LineItemPreset,
+};
+
+#[tokio::test]
+async fn test_campaign_from_advertiser_default_preset() {
+    let client = AsyncInMemoryClient::new();
+
+    let campaign_preset = client
+        .create(
+            &CampaignPreset::create_builder()
+                .campaign_preset_name("House defaults")
+                .campaign_budget(1000.0)
+                .budget_type(BudgetType::Impressions)
+                .currency(Currency::Euro)
+                .frequency_cap(vec![FrequencyCap {
+                    duration: Some(86400),
+                    impressions: Some(3),
+                }])
+                .build(),
+        )
+        .await
+        .unwrap();
+    let advertiser = Advertiser {
+        advertiser_id: 1,
+        default_campaign_preset_id: Some(campaign_preset.campaign_preset_id),
+        ..Default::default()
+    };
+
+    let read_campaign_preset = ReadCampaignPreset::advertiser_default(&advertiser).unwrap();
+    let campaign_presets = client.read(&read_campaign_preset).await.unwrap();
+    assert_eq!(campaign_presets, vec![campaign_preset.clone()]);
+
+    let create_campaign = campaign_preset.create_campaign(
+        advertiser.advertiser_id,
+        "Winter",
+        500.0,
+        "2020-01-01 00:00:00",
+    );
+    let campaign: Campaign = client.create(&create_campaign).await.unwrap();
+
+    assert_eq!(campaign.advertiser_id, 1);
+    assert_eq!(campaign.campaign_name, "Winter");
+    assert_eq!(campaign.campaign_budget, 500.0);
+    assert_eq!(campaign.start_date, "2020-01-01 00:00:00");
+    assert_eq!(campaign.budget_type, Some(BudgetType::Impressions));
+    assert_eq!(campaign.currency, Some(Currency::Euro));
+    assert_eq!(campaign.frequency_cap, campaign_preset.frequency_cap);
+}
+
+#[tokio::test]
+async fn test_line_item_from_preset() {
+    let client = AsyncInMemoryClient::new();
+
+    let line_item_preset = client
+        .create(
+            &LineItemPreset::create_builder()
+                .line_item_preset_name("Video defaults")
+                .line_item_type_id(1)
+                .max_bid(8.0)
+                .creative_weighting_method(WeightingMethod::WEIGHTED)
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    let create_line_item =
+        line_item_preset.create_line_item(1, 2, "Video", 250.0, "2020-01-01 00:00:00");
+    assert_eq!(create_line_item.advertiser_id, 1);
+    assert_eq!(create_line_item.campaign_id, 2);
+    assert_eq!(create_line_item.line_item_type_id, 1);
+    assert_eq!(create_line_item.line_item_name, "Video");
+    assert_eq!(create_line_item.line_item_budget, 250.0);
+    assert_eq!(create_line_item.start_date, "2020-01-01 00:00:00");
+    assert_eq!(create_line_item.max_bid, Some(8.0));
+    assert_eq!(
+        create_line_item.creative_weighting_method,
+        Some(WeightingMethod::WEIGHTED)
+    );
+    let _line_item: LineItem = client.create(&create_line_item).await.unwrap();
+
+    let advertiser = Advertiser::default();
+    assert!(ReadLineItemPreset::advertiser_default(&advertiser).is_none());
+}
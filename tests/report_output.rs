@@ -0,0 +1,108 @@
+extern crate beeswax;
+
+use beeswax::client::{async_client::AsyncInMemoryClient, sync_client::SyncInMemoryClient};
+use beeswax::resource::{report_output::ReportOutput, report_queue::ReportFileFormat, ReportQueue};
+use futures_util::io::Cursor;
+use serde::Deserialize;
+
+const REPORT: &str = "domain,impressions\nexample.com,100\n\"a, \"\"quoted\"\"\nname\",\n";
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Row {
+    domain: String,
+    impressions: Option<u64>,
+}
+
+#[test]
+fn test_stream_csv_to_tsv() {
+    let mut tsv = Vec::new();
+
+    let rows = ReportFileFormat::Csv
+        .stream(REPORT.as_bytes(), ReportOutput::Tsv, &mut tsv)
+        .unwrap();
+
+    assert_eq!(rows, 2);
+    assert_eq!(
+        String::from_utf8(tsv).unwrap(),
+        "domain\timpressions\nexample.com\t100\n\"a, \"\"quoted\"\"\nname\"\t\n"
+    );
+}
+
+#[test]
+fn test_stream_json_lines() {
+    let mut json_lines = Vec::new();
+
+    ReportFileFormat::Csv
+        .stream(REPORT.as_bytes(), ReportOutput::JsonLines, &mut json_lines)
+        .unwrap();
+
+    let lines: Vec<serde_json::Value> = String::from_utf8(json_lines)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    assert_eq!(
+        lines,
+        vec![
+            serde_json::json!({"domain": "example.com", "impressions": "100"}),
+            serde_json::json!({"domain": "a, \"quoted\"\nname", "impressions": null}),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_stream_async_matches_sync() {
+    let mut report = String::from("domain\timpressions\n");
+    for i in 0..5000 {
+        report.push_str(&format!("{}{}.com\t{}\n", "x".repeat(i % 2000), i, i));
+    }
+
+    let mut sync_csv = Vec::new();
+    let sync_rows = ReportFileFormat::Tsv
+        .stream(report.as_bytes(), ReportOutput::Csv, &mut sync_csv)
+        .unwrap();
+    let mut async_csv = Vec::new();
+    let async_rows = ReportFileFormat::Tsv
+        .stream_async(
+            Cursor::new(report.as_bytes()),
+            ReportOutput::Csv,
+            &mut async_csv,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(sync_rows, 5000);
+    assert_eq!(async_rows, sync_rows);
+    assert_eq!(async_csv, sync_csv);
+}
+
+#[test]
+fn test_rows_iter_in_memory() {
+    let beeswax_client = SyncInMemoryClient::new();
+    let report_queue = ReportQueue::default();
+
+    let rows = report_queue
+        .rows_iter_sync::<_, Row>(&beeswax_client)
+        .unwrap()
+        .count();
+
+    assert_eq!(rows, 0);
+}
+
+#[tokio::test]
+async fn test_row_reader_in_memory() {
+    let beeswax_client = AsyncInMemoryClient::new();
+    let report_queue = ReportQueue::default();
+
+    let mut row_reader = beeswax_client
+        .report_row_reader::<Row>(&report_queue)
+        .await
+        .unwrap();
+
+    assert!(row_reader.next_row().await.unwrap().is_none());
+    assert!(beeswax_client
+        .report_columns(&report_queue)
+        .await
+        .unwrap()
+        .is_empty());
+}
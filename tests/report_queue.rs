@@ -0,0 +1,124 @@
+extern crate beeswax;
+
+use beeswax::client::{
+    sync_client::{SyncClient, SyncInMemoryClient},
+    PollOptions,
+};
+use beeswax::resource::{
+    report_queue::{ReportFileFormat, ReportStatus},
+    Create, ReportQueue,
+};
+use serde::Deserialize;
+use std::time::Duration;
+
+#[derive(Debug, Deserialize, PartialEq)]
+struct Row {
+    campaign_id: u64,
+    impressions: u64,
+    spend: f64,
+}
+
+#[test]
+fn test_parse_csv_rows() {
+    let report = b"campaign_id,impressions,spend\n1,100,1.5\n2,200,3.25\n";
+
+    let rows: Vec<Row> = ReportFileFormat::Csv.parse_rows(report).unwrap();
+
+    assert_eq!(
+        rows,
+        vec![
+            Row {
+                campaign_id: 1,
+                impressions: 100,
+                spend: 1.5
+            },
+            Row {
+                campaign_id: 2,
+                impressions: 200,
+                spend: 3.25
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_parse_tsv_rows() {
+    let report = b"campaign_id\timpressions\tspend\n1\t100\t1.5\n";
+
+    let rows: Vec<Row> = ReportFileFormat::Tsv.parse_rows(report).unwrap();
+
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0].impressions, 100);
+}
+
+#[test]
+fn test_parse_rows_missing_column() {
+    let report = b"campaign_id,impressions\n1,100\n";
+
+    assert!(ReportFileFormat::Csv.parse_rows::<Row>(report).is_err());
+}
+
+#[test]
+fn test_failed_report() {
+    let report_queue = ReportQueue {
+        report_status: Some(ReportStatus::Failed),
+        ..Default::default()
+    };
+
+    assert!(report_queue.is_complete().is_err());
+}
+
+#[test]
+fn test_report_without_status_is_pending() {
+    let report_queue = ReportQueue::default();
+
+    assert!(!report_queue.is_complete().unwrap());
+}
+
+#[test]
+fn test_created_report_is_queued() {
+    let create_report_queue = ReportQueue::create_builder()
+        .view_name("performance_agg")
+        .fields(vec!["campaign_id".to_string()])
+        .build();
+
+    let report_queue = create_report_queue.clone().into_resource(1);
+    assert_eq!(report_queue.report_status, Some(ReportStatus::Queued));
+    assert!(!report_queue.is_complete().unwrap());
+
+    let beeswax_client = SyncInMemoryClient::new();
+    let report_queue = beeswax_client.create(&create_report_queue).unwrap();
+    assert!(report_queue.is_complete().unwrap());
+}
+
+#[test]
+fn test_wait_for_report_without_status() {
+    let beeswax_client = SyncInMemoryClient::new();
+    let report_queue = ReportQueue {
+        report_queue_id: 1,
+        ..Default::default()
+    };
+    beeswax_client.insert(report_queue.clone());
+    let poll = PollOptions {
+        interval: Duration::from_millis(1),
+        max_attempts: 3,
+    };
+
+    assert!(report_queue.wait_sync(&beeswax_client, poll).is_err());
+}
+
+#[test]
+fn test_run_report_in_memory() {
+    let beeswax_client = SyncInMemoryClient::new();
+    let create_report_queue = ReportQueue::create_builder()
+        .view_name("performance_agg")
+        .fields(vec!["campaign_id".to_string(), "impressions".to_string()])
+        .date_filter("yesterday".to_string())
+        .build();
+
+    let rows: Vec<Row> = create_report_queue
+        .run_sync(&beeswax_client, PollOptions::default())
+        .unwrap();
+
+    assert!(rows.is_empty());
+}
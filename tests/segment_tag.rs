@@ -0,0 +1,43 @@
+extern crate beeswax;
+
+use beeswax::client::{async_client::AsyncInMemoryClient, sync_client::SyncInMemoryClient};
+use beeswax::resource::{common::EventTagType, segment_tag::ReadSegmentTag, SegmentTag};
+
+fn segment_tag(segment_id: u64, tag_type: EventTagType, tag: &str) -> SegmentTag {
+    SegmentTag {
+        segment_id,
+        tag_type,
+        tag: tag.to_string(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_segment_tag_markup() {
+    let beeswax_client = SyncInMemoryClient::new();
+    beeswax_client.insert(segment_tag(1, EventTagType::ImagePixel, "<img>"));
+    beeswax_client.insert(segment_tag(1, EventTagType::JavaScript, "<script>"));
+    beeswax_client.insert(segment_tag(2, EventTagType::JavaScript, "<script 2>"));
+
+    let markup = ReadSegmentTag::new(1, EventTagType::JavaScript)
+        .markup_sync(&beeswax_client)
+        .unwrap();
+
+    assert_eq!(markup, "<script>");
+    assert!(ReadSegmentTag::new(2, EventTagType::Iframe)
+        .markup_sync(&beeswax_client)
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_segment_tag_markup_async() {
+    let beeswax_client = AsyncInMemoryClient::new();
+    beeswax_client.insert(segment_tag(1, EventTagType::ImagePixel, "<img>"));
+
+    let markup = beeswax_client
+        .segment_tag_markup(1, EventTagType::ImagePixel)
+        .await
+        .unwrap();
+
+    assert_eq!(markup, "<img>");
+}
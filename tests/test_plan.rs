@@ -0,0 +1,105 @@
+extern crate beeswax;
+
+use beeswax::client::async_client::AsyncInMemoryClient;
+use beeswax::resource::{Campaign, LineItem, TestGroup, TestPlan};
+
+fn test_group(test_group_id: u64, test_plan_id: u64, percentage: u8) -> TestGroup {
+    TestGroup {
+        test_group_id,
+        test_plan_id,
+        percentage,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_validate_groups() {
+    let test_plan = TestPlan {
+        test_plan_id: 1,
+        ..Default::default()
+    };
+
+    assert!(test_plan
+        .validate_groups(&[test_group(1, 1, 90), test_group(2, 1, 10)])
+        .is_ok());
+    assert!(test_plan
+        .validate_groups(&[test_group(1, 1, 90), test_group(2, 1, 20)])
+        .is_err());
+    assert!(test_plan.validate_groups(&[test_group(1, 2, 10)]).is_err());
+}
+
+#[tokio::test]
+async fn test_holdout_setup() {
+    let client = AsyncInMemoryClient::new();
+
+    let test_plan = client
+        .create(
+            &TestPlan::create_builder()
+                .test_plan_name("Retargeting holdout")
+                .build(),
+        )
+        .await
+        .unwrap();
+    for (name, percentage) in &[("Exposed", 90), ("Holdout", 10)] {
+        client
+            .create(
+                &TestGroup::create_builder()
+                    .test_plan_id(test_plan.test_plan_id)
+                    .test_group_name(*name)
+                    .percentage(*percentage)
+                    .build(),
+            )
+            .await
+            .unwrap();
+    }
+
+    let test_groups = client
+        .read(
+            &TestGroup::read_builder()
+                .test_plan_id(test_plan.test_plan_id)
+                .build(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(test_groups.len(), 2);
+    assert!(test_plan.validate_groups(&test_groups).is_ok());
+
+    let campaign = client
+        .create(
+            &Campaign::create_builder()
+                .advertiser_id(1)
+                .campaign_name("Experiment")
+                .test_plan_id(test_plan.test_plan_id)
+                .build(),
+        )
+        .await
+        .unwrap();
+    let campaigns = client
+        .read(
+            &Campaign::read_builder()
+                .test_plan_id(test_plan.test_plan_id)
+                .build(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(campaigns.len(), 1);
+    assert_eq!(campaign.test_plan_id, Some(test_plan.test_plan_id));
+}
+
+#[tokio::test]
+async fn test_line_item_test_plan() {
+    let client = AsyncInMemoryClient::new();
+    let mut line_item = serde_json::to_value(LineItem::default()).unwrap();
+    line_item["line_item_id"] = 5.into();
+    line_item["test_plan_id"] = 3.into();
+    line_item["test_group_id"] = 4.into();
+    let line_item: LineItem = serde_json::from_value(line_item).unwrap();
+    assert_eq!(line_item.test_plan_id(), Some(3));
+    assert_eq!(line_item.test_group_id(), Some(4));
+    client.insert(line_item.clone());
+
+    let read_line_item = LineItem::read_builder().test_plan_id(3).build();
+    assert_eq!(client.read(&read_line_item).await.unwrap(), vec![line_item]);
+    let read_line_item = LineItem::read_builder().test_plan_id(4).build();
+    assert!(client.read(&read_line_item).await.unwrap().is_empty());
+}
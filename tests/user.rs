@@ -0,0 +1,118 @@
+extern crate beeswax;
+
+use beeswax::client::async_client::AsyncInMemoryClient;
+use beeswax::resource::{
+    role::{Access, ObjectType, PermissionSet},
+    Account, Role, User,
+};
+
+#[test]
+fn test_permission_levels() {
+    let permissions = PermissionSet::new()
+        .grant(ObjectType::LineItem, Access::Delete)
+        .grant(ObjectType::Report, Access::Read);
+
+    assert!(permissions.allows(&ObjectType::LineItem, Access::Write));
+    assert!(permissions.allows(&ObjectType::Report, Access::Read));
+    assert!(!permissions.allows(&ObjectType::Report, Access::Write));
+    assert_eq!(permissions.access(&ObjectType::User), Access::None);
+}
+
+#[test]
+fn test_permission_set_json() {
+    let permissions: PermissionSet =
+        serde_json::from_str(r#"{"campaign": "write", "new_thing": "read"}"#).unwrap();
+
+    assert_eq!(permissions.access(&ObjectType::Campaign), Access::Write);
+    assert_eq!(
+        permissions.access(&ObjectType::Other("new_thing".to_string())),
+        Access::Read
+    );
+    assert_eq!(
+        serde_json::to_string(&PermissionSet::new().grant(ObjectType::LineItem, Access::Read))
+            .unwrap(),
+        r#"{"line_item":"read"}"#
+    );
+}
+
+#[test]
+fn test_permission_set_unknown_object_types() {
+    let json = r#"{"campaign":"write","new_thing":"read","other_thing":"delete"}"#;
+
+    let permissions: PermissionSet = serde_json::from_str(json).unwrap();
+
+    assert_eq!(
+        permissions.access(&ObjectType::Other("new_thing".to_string())),
+        Access::Read
+    );
+    assert_eq!(
+        permissions.access(&ObjectType::Other("other_thing".to_string())),
+        Access::Delete
+    );
+    assert_eq!(serde_json::to_string(&permissions).unwrap(), json);
+}
+
+#[test]
+fn test_authenticate_new_user() {
+    let create_user = User::create_builder()
+        .email("trader@example.com")
+        .role_id(1)
+        .account_id(7)
+        .password("secret".to_string())
+        .build();
+
+    let authenticate = create_user.authenticate().unwrap().unwrap();
+    assert_eq!(authenticate.email, "trader@example.com");
+    assert_eq!(authenticate.password, "secret");
+    assert_eq!(authenticate.account_id, Some(7));
+
+    let no_password = User::create_builder()
+        .email("trader@example.com")
+        .role_id(1)
+        .build();
+    assert!(no_password.authenticate().unwrap().is_none());
+
+    let large_account = User::create_builder()
+        .email("trader@example.com")
+        .role_id(1)
+        .account_id(u64::from(u32::MAX))
+        .password("secret".to_string())
+        .build();
+    assert!(large_account.authenticate().is_err());
+}
+
+#[tokio::test]
+async fn test_provision_account() {
+    let client = AsyncInMemoryClient::new();
+
+    let account = client
+        .create(&Account::create_builder().account_name("Agency").build())
+        .await
+        .unwrap();
+    let role = client
+        .create(
+            &Role::create_builder()
+                .role_name("Trader")
+                .permissions(PermissionSet::new().grant(ObjectType::Campaign, Access::Write))
+                .build(),
+        )
+        .await
+        .unwrap();
+    let user = client
+        .create(
+            &User::create_builder()
+                .email("trader@example.com")
+                .role_id(role.role_id)
+                .account_id(account.account_id)
+                .build(),
+        )
+        .await
+        .unwrap();
+
+    let users = client
+        .read(&User::read_builder().role_id(role.role_id).build())
+        .await
+        .unwrap();
+    assert_eq!(users, vec![user.clone()]);
+    client.delete(&user).await.unwrap();
+}
@@ -0,0 +1,125 @@
+extern crate beeswax;
+
+use crate::helper::random_string;
+use beeswax::client::{
+    async_client::AsyncInMemoryClient, sync_client::SyncInMemoryClient, PollOptions,
+};
+use beeswax::resource::{
+    advertiser::CreateAdvertiser, video_asset::TranscodeStatus, Advertiser, VideoAsset,
+};
+use futures_timer::Delay;
+use std::time::Duration;
+
+mod helper;
+
+fn video_asset(transcode_status: Option<TranscodeStatus>) -> VideoAsset {
+    VideoAsset {
+        video_asset_id: 10,
+        transcode_status,
+        ..Default::default()
+    }
+}
+
+fn poll() -> PollOptions {
+    PollOptions {
+        interval: Duration::from_millis(1),
+        max_attempts: 1000,
+    }
+}
+
+#[tokio::test]
+async fn test_wait_for_transcode_queued_to_complete() {
+    let client = AsyncInMemoryClient::new();
+    client.insert(video_asset(Some(TranscodeStatus::Queued)));
+    let queued = video_asset(Some(TranscodeStatus::Queued));
+
+    let transcode = async {
+        Delay::new(Duration::from_millis(10)).await;
+        let complete = video_asset(Some(TranscodeStatus::Complete));
+        client.update(&complete).await.unwrap();
+    };
+    let (video_asset, _) = tokio::join!(client.wait_for_transcode(&queued, poll()), transcode);
+
+    assert_eq!(
+        video_asset.unwrap().transcode_status,
+        Some(TranscodeStatus::Complete)
+    );
+}
+
+#[test]
+fn test_wait_for_transcode_failed() {
+    let client = SyncInMemoryClient::new();
+    client.insert(VideoAsset {
+        transcode_error: Some("unsupported codec".to_string()),
+        ..video_asset(Some(TranscodeStatus::Failed))
+    });
+
+    let error = video_asset(None)
+        .wait_for_transcode_sync(&client, poll())
+        .unwrap_err();
+    assert!(error.to_string().contains("unsupported codec"));
+}
+
+#[test]
+fn test_wait_for_transcode_timeout() {
+    let client = SyncInMemoryClient::new();
+    client.insert(video_asset(None));
+
+    let poll = PollOptions {
+        max_attempts: 3,
+        ..poll()
+    };
+    let error = video_asset(None)
+        .wait_for_transcode_sync(&client, poll)
+        .unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("not transcoded after 3 attempts"));
+}
+
+#[tokio::test]
+async fn test_video_asset() {
+    let beeswax_client = helper::get_async_beeswax_client().await.unwrap();
+
+    let create_advertiser = CreateAdvertiser {
+        advertiser_name: random_string("Advertiser name"),
+        ..Default::default()
+    };
+
+    let advertiser = beeswax_client.create(&create_advertiser).await.unwrap();
+
+    let create_video_asset = VideoAsset::create_builder()
+        .advertiser_id(advertiser.advertiser_id)
+        .video_asset_name(random_string("Video asset name"))
+        .mime_type("video/mp4".to_string())
+        .build();
+
+    let video_asset = beeswax_client.create(&create_video_asset).await.unwrap();
+
+    let read_video_asset = VideoAsset::read_builder()
+        .video_asset_id(video_asset.video_asset_id)
+        .build();
+
+    let read_video_asset = beeswax_client
+        .read(&read_video_asset)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    assert_eq!(read_video_asset.video_asset_id, video_asset.video_asset_id);
+    assert_eq!(read_video_asset.advertiser_id, video_asset.advertiser_id);
+    assert_eq!(
+        read_video_asset.video_asset_name,
+        video_asset.video_asset_name
+    );
+    assert_eq!(read_video_asset.mime_type, video_asset.mime_type);
+
+    beeswax_client.delete(&read_video_asset).await.unwrap();
+
+    let delete_advertiser = Advertiser::delete_builder()
+        .advertiser_id(advertiser.advertiser_id)
+        .build();
+
+    beeswax_client.delete(&delete_advertiser).await.unwrap();
+}
@@ -1,6 +1,10 @@
 extern crate beeswax;
 
-use beeswax::resource::{common::ViewName, view::ReadView};
+use beeswax::resource::{
+    common::ViewName,
+    view::ReadView,
+    view_row::{Country, CreativeSize},
+};
 
 mod helper;
 
@@ -8,17 +12,31 @@ mod helper;
 async fn test_view() {
     let beeswax_client = helper::get_async_beeswax_client().await.unwrap();
 
-    let read_view = ReadView {
-        view_name: ViewName::Continents,
-    };
+    let read_view = ReadView::builder().view_name(ViewName::Continents).build();
 
     let _response = beeswax_client.read(&read_view).await.unwrap();
     // dbg!(_response);
 
-    let read_view = ReadView {
-        view_name: ViewName::Currency,
-    };
+    let read_view = ReadView::builder().view_name(ViewName::Currency).build();
 
     let _response = beeswax_client.read(&read_view).await.unwrap();
     // dbg!(_response);
 }
+
+#[tokio::test]
+async fn test_read_view_typed() {
+    let beeswax_client = helper::get_async_beeswax_client().await.unwrap();
+
+    let countries = beeswax_client
+        .read_view::<Country>(ViewName::Countries)
+        .await
+        .unwrap();
+    assert!(countries
+        .iter()
+        .any(|country| country.country_code == "USA"));
+
+    let _sizes = beeswax_client
+        .read_view::<CreativeSize>(ViewName::CreativeSizes)
+        .await
+        .unwrap();
+}
@@ -0,0 +1,85 @@
+extern crate beeswax;
+
+use beeswax::client::async_client::AsyncInMemoryClient;
+use beeswax::client::sync_client::SyncInMemoryClient;
+use beeswax::resource::{
+    common::ViewName,
+    view::ReadView,
+    view_list::{DisplayType, ViewList},
+    View,
+};
+use serde_json::json;
+
+#[test]
+fn test_view_list_field() {
+    let view_list: ViewList = serde_json::from_value(json!({
+        "field_name": "spend",
+        "field_label": "Spend",
+        "display_type": "currency",
+        "sort_order": 2
+    }))
+    .unwrap();
+
+    let field = view_list.to_field().unwrap();
+
+    assert_eq!(field.label(), "Spend");
+    assert_eq!(field.display_type, Some(DisplayType::Currency));
+    assert_eq!(field.sort_order, Some(2));
+}
+
+#[test]
+fn test_unknown_display_type() {
+    let view_list: ViewList =
+        serde_json::from_value(json!({"field_name": "flag", "display_type": "emoji"})).unwrap();
+
+    assert_eq!(
+        view_list.to_field().unwrap().display_type,
+        Some(DisplayType::Other)
+    );
+}
+
+#[test]
+fn test_view_columns() {
+    let beeswax_client = SyncInMemoryClient::new();
+    beeswax_client.insert(View::new(
+        ViewName::Currency,
+        json!({"currency_code": "USD", "currency_name": "US Dollar", "symbol": "$"}),
+    ));
+    beeswax_client.insert(View::new(
+        ViewName::Currency,
+        json!({"currency_code": "EUR", "currency_name": "Euro"}),
+    ));
+    beeswax_client.insert(ViewList::new(
+        ViewName::Currency,
+        json!({"field_name": "currency_name", "field_label": "Name", "sort_order": 1}),
+    ));
+    beeswax_client.insert(ViewList::new(
+        ViewName::Currency,
+        json!({"field_name": "currency_code", "field_label": "Code", "sort_order": 2}),
+    ));
+    beeswax_client.insert(ViewList::new(
+        ViewName::Countries,
+        json!({"field_name": "country_code", "sort_order": 0}),
+    ));
+
+    let columns = ReadView::builder()
+        .view_name(ViewName::Currency)
+        .build()
+        .view_columns_sync(&beeswax_client)
+        .unwrap();
+
+    let labels: Vec<&str> = columns.iter().map(|column| column.field.label()).collect();
+    assert_eq!(labels, vec!["Name", "Code", "symbol"]);
+    assert_eq!(columns[1].values, vec![json!("USD"), json!("EUR")]);
+    assert_eq!(columns[2].values, vec![json!("$"), json!(null)]);
+}
+
+#[tokio::test]
+async fn test_view_columns_async() {
+    let beeswax_client = AsyncInMemoryClient::new();
+    let read_view = ReadView::builder().view_name(ViewName::Dma).build();
+
+    let columns = beeswax_client.view_columns(&read_view).await.unwrap();
+
+    assert!(columns.is_empty());
+}
@@ -0,0 +1,151 @@
+extern crate beeswax;
+
+use beeswax::client::sync_client::SyncInMemoryClient;
+use beeswax::prelude::SyncClient;
+use beeswax::resource::{
+    common::ViewName,
+    view::{ReadView, View},
+    view_row::{Country, CreativeSize, Dma},
+};
+use serde_json::json;
+
+fn view(row: serde_json::Value) -> View {
+    serde_json::from_value(row).unwrap()
+}
+
+#[test]
+fn test_view_into_row() {
+    let country: Country = view(json!({
+        "country_code": "USA",
+        "country_name": "United States",
+        "not_modelled": 1
+    }))
+    .into_row()
+    .unwrap();
+
+    assert_eq!(country.country_code, "USA");
+    assert_eq!(country.country_name, Some("United States".to_string()));
+    assert_eq!(country.continent, None);
+}
+
+#[test]
+fn test_view_into_row_wrong_shape() {
+    let size = view(json!({"width": "wide"})).into_row::<CreativeSize>();
+
+    assert!(size.is_err());
+}
+
+#[test]
+fn test_read_view_in_memory() {
+    let beeswax_client = SyncInMemoryClient::new();
+
+    let dmas = beeswax_client.read_view::<Dma>(ViewName::Dma).unwrap();
+
+    assert!(dmas.is_empty());
+}
+
+fn client_with_countries() -> SyncInMemoryClient {
+    let beeswax_client = SyncInMemoryClient::new();
+    for (code, continent) in &[("USA", "NAM"), ("CAN", "NAM"), ("GBR", "EMEA")] {
+        beeswax_client.insert(View::new(
+            ViewName::Countries,
+            json!({"country_code": code, "continent": continent}),
+        ));
+    }
+    beeswax_client.insert(View::new(ViewName::Dma, json!({"dma_code": 501})));
+    beeswax_client
+}
+
+#[test]
+fn test_read_view_canned_rows() {
+    let beeswax_client = client_with_countries();
+
+    let countries = beeswax_client
+        .read_view::<Country>(ViewName::Countries)
+        .unwrap();
+    let dmas = beeswax_client.read_view::<Dma>(ViewName::Dma).unwrap();
+
+    assert_eq!(countries.len(), 3);
+    assert_eq!(dmas[0].dma_code, 501);
+}
+
+#[test]
+fn test_read_view_filter_and_page() {
+    let beeswax_client = client_with_countries();
+    let read_view = ReadView::builder()
+        .view_name(ViewName::Countries)
+        .offset(1)
+        .rows(5)
+        .build()
+        .filter("continent", "NAM");
+
+    let countries = beeswax_client
+        .read_view_rows::<Country>(&read_view)
+        .unwrap();
+
+    assert_eq!(countries.len(), 1);
+    assert_eq!(countries[0].country_code, "CAN");
+}
+
+#[test]
+fn test_read_view_fields() {
+    let beeswax_client = client_with_countries();
+    let read_view = ReadView::builder()
+        .view_name(ViewName::Countries)
+        .fields(vec!["country_code".to_string()])
+        .build();
+
+    let views = beeswax_client.read(&read_view).unwrap();
+
+    assert_eq!(*views[0], json!({"country_code": "USA"}));
+}
+
+#[test]
+fn test_read_view_query_string() {
+    let read_view = ReadView::builder()
+        .view_name(ViewName::LookupDomains)
+        .fields(vec!["domain".to_string(), "rank".to_string()])
+        .rows(10)
+        .build()
+        .filter("domain", "example.com");
+
+    assert_eq!(
+        serde_urlencoded::to_string(&read_view).unwrap(),
+        "view_name=lookup_domains&fields=domain%2Crank&rows=10&domain=example.com"
+    );
+}
+
+#[test]
+fn test_custom_view_name() {
+    let custom = ViewName::Custom("account_brands".to_string());
+    let read_view = ReadView::builder().view_name(custom.clone()).build();
+
+    assert_eq!(
+        serde_urlencoded::to_string(&read_view).unwrap(),
+        "view_name=account_brands"
+    );
+    assert_eq!(
+        serde_json::from_value::<ViewName>(json!("account_brands")).unwrap(),
+        custom
+    );
+    assert_eq!(
+        serde_json::from_value::<ViewName>(json!("DMA")).unwrap(),
+        ViewName::Dma
+    );
+}
+
+#[test]
+fn test_read_custom_view_in_memory() {
+    let beeswax_client = SyncInMemoryClient::new();
+    beeswax_client.insert(View::new(
+        ViewName::from("account_brands"),
+        json!({"brand": "Example"}),
+    ));
+    beeswax_client.insert(View::new(ViewName::Countries, json!({"brand": "Other"})));
+
+    let brands = beeswax_client
+        .read_view::<serde_json::Value>(ViewName::Custom("account_brands".to_string()))
+        .unwrap();
+
+    assert_eq!(brands, vec![json!({"brand": "Example"})]);
+}